@@ -0,0 +1,510 @@
+//! Parsing of the TOML front matter at the top of every post in `assets/`,
+//! of every series in `assets/series/` and every author in `assets/authors/`.
+//!
+//! A post starts with a block delimited by `+++` lines:
+//!
+//! ```text
+//! +++
+//! title = "Post 1"
+//! authors = ["author-1"]
+//! published = 2021-01-01
+//! category = "software/compilers"
+//! tags = ["rust", "web-dev"]
+//! +++
+//!
+//! ## This is the first post
+//! ```
+//!
+//! `published` can also give a time, `2021-01-01T09:00:00+02:00`, before which
+//! the post is scheduled rather than out, and `status` is `"draft"`,
+//! `"unlisted"`, `"published"` (the default) or `"archived"`.
+//!
+//! A series in `assets/series/` has the same shape: a `title`, a `status` of
+//! `"ongoing"` or `"complete"` and an optional `cover` image, followed by
+//! its description. So does an author in `assets/authors/`: a `name`, an
+//! optional `avatar` image, `website` and `links`, followed by their bio if
+//! they have one.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use toml::value::Offset;
+use toml::{Table, Value};
+
+const DELIMITER: &str = "+++";
+
+/// An error in a post's front matter, pointing at the file and (where it
+/// applies) the offending field.
+#[derive(Debug)]
+pub struct Error {
+    pub path: PathBuf,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl Error {
+    fn new(path: &Path, field: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            field: field.map(str::to_string),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path.display())?;
+        if let Some(field) = &self.field {
+            write!(f, "field `{}`: ", field)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// The time of day a post goes out, for one that doesn't at midnight UTC.
+#[derive(Clone, Copy, Debug)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// Minutes ahead of UTC
+    pub offset_minutes: i16,
+}
+
+/// Where a post is in its life. Drafts only make it into debug builds, and
+/// unlisted posts are left out of listings, search and feeds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Draft,
+    Unlisted,
+    Published,
+    Archived,
+}
+
+#[derive(Debug)]
+pub struct FrontMatter {
+    pub title: String,
+    /// Ids of entries in `assets/authors/`
+    pub authors: Vec<String>,
+    pub published: Date,
+    pub published_time: Option<Time>,
+    pub status: Status,
+    pub category: Option<String>,
+    pub series: Option<String>,
+    pub part: Option<u32>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Whether a series has all of its parts yet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeriesStatus {
+    Ongoing,
+    Complete,
+}
+
+#[derive(Debug)]
+pub struct SeriesFrontMatter {
+    pub title: String,
+    pub status: SeriesStatus,
+    /// Image shown at the top of the series page
+    pub cover: Option<String>,
+}
+
+/// A series as found in `assets/series/`: its slug (the file stem), its
+/// parsed front matter and the Markdown description that follows it.
+#[derive(Debug)]
+pub struct SeriesSource {
+    pub slug: String,
+    pub path: PathBuf,
+    pub meta: SeriesFrontMatter,
+    pub description: String,
+}
+
+#[derive(Debug)]
+pub struct AuthorFrontMatter {
+    pub name: String,
+    pub avatar: Option<String>,
+    pub website: Option<String>,
+    /// Label and URL of each profile elsewhere
+    pub links: Vec<(String, String)>,
+}
+
+/// An author as found in `assets/authors/`: their id (the file stem), their
+/// parsed front matter and the Markdown bio that follows it.
+#[derive(Debug)]
+pub struct AuthorSource {
+    pub id: String,
+    pub path: PathBuf,
+    pub meta: AuthorFrontMatter,
+    pub bio: String,
+}
+
+/// A post as found on disk: its id (the file stem), its parsed front matter
+/// and the Markdown that follows it.
+#[derive(Debug)]
+pub struct PostSource {
+    pub id: String,
+    pub path: PathBuf,
+    pub meta: FrontMatter,
+    pub body: String,
+}
+
+pub fn parse_post(path: &Path, source: &str) -> Result<PostSource, Error> {
+    let (id, mut table, body) = parse_document(path, source)?;
+    let mut fields = Fields {
+        table: &mut table,
+        path,
+    };
+    let title = fields.required_string("title")?;
+    let authors = fields.required_authors("authors")?;
+    let (published, published_time) = fields.required_datetime("published")?;
+    let meta = FrontMatter {
+        title,
+        authors,
+        published,
+        published_time,
+        status: fields.status("status")?,
+        category: fields.optional_category("category")?,
+        series: fields.optional_string("series")?,
+        part: fields.optional_part("part")?,
+        description: fields.optional_string("description")?,
+        tags: fields.tags("tags")?,
+    };
+
+    if let Some(unknown) = table.keys().next() {
+        return Err(Error::new(path, Some(unknown), "unknown field"));
+    }
+
+    Ok(PostSource {
+        id,
+        path: path.to_path_buf(),
+        meta,
+        body: body.to_string(),
+    })
+}
+
+pub fn parse_series(path: &Path, source: &str) -> Result<SeriesSource, Error> {
+    let (slug, mut table, description) = parse_document(path, source)?;
+    let mut fields = Fields {
+        table: &mut table,
+        path,
+    };
+    let meta = SeriesFrontMatter {
+        title: fields.required_string("title")?,
+        status: match fields.required_string("status")?.as_str() {
+            "ongoing" => SeriesStatus::Ongoing,
+            "complete" => SeriesStatus::Complete,
+            other => {
+                return Err(Error::new(
+                    path,
+                    Some("status"),
+                    format!("expected \"ongoing\" or \"complete\", found \"{other}\""),
+                ))
+            }
+        },
+        cover: fields.optional_string("cover")?,
+    };
+
+    if let Some(unknown) = table.keys().next() {
+        return Err(Error::new(path, Some(unknown), "unknown field"));
+    }
+
+    Ok(SeriesSource {
+        slug,
+        path: path.to_path_buf(),
+        meta,
+        description: description.to_string(),
+    })
+}
+
+pub fn parse_author(path: &Path, source: &str) -> Result<AuthorSource, Error> {
+    let (id, mut table, bio) = parse_document(path, source)?;
+    let mut fields = Fields {
+        table: &mut table,
+        path,
+    };
+    let meta = AuthorFrontMatter {
+        name: fields.required_string("name")?,
+        avatar: fields.optional_string("avatar")?,
+        website: fields.optional_string("website")?,
+        links: fields.links("links")?,
+    };
+
+    if let Some(unknown) = table.keys().next() {
+        return Err(Error::new(path, Some(unknown), "unknown field"));
+    }
+
+    Ok(AuthorSource {
+        id,
+        path: path.to_path_buf(),
+        meta,
+        bio: bio.to_string(),
+    })
+}
+
+/// Splits a file into the file stem, which names it in URLs, its front
+/// matter table and the Markdown after it.
+fn parse_document<'a>(path: &Path, source: &'a str) -> Result<(String, Table, &'a str), Error> {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| Error::new(path, None, "file name is not valid UTF-8"))?
+        .to_string();
+
+    let (header, body) = split(source).ok_or_else(|| {
+        Error::new(
+            path,
+            None,
+            format!("missing front matter (expected the file to start with a `{DELIMITER}` block)"),
+        )
+    })?;
+
+    let table: Table = header.parse().map_err(|e: toml::de::Error| {
+        Error::new(path, None, format!("invalid TOML: {}", e.message()))
+    })?;
+    Ok((stem, table, body))
+}
+
+/// Splits a file into its front matter and the rest of the document.
+fn split(source: &str) -> Option<(&str, &str)> {
+    let rest = source.strip_prefix(DELIMITER)?;
+    let rest = rest
+        .strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == DELIMITER {
+            let body = &rest[offset + line.len()..];
+            let body = body.trim_start_matches(['\r', '\n']);
+            return Some((&rest[..offset], body));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Pulls typed fields out of the front matter table, removing each one so
+/// that whatever remains afterwards can be reported as unknown.
+struct Fields<'a> {
+    table: &'a mut Table,
+    path: &'a Path,
+}
+
+impl Fields<'_> {
+    fn required_string(&mut self, field: &str) -> Result<String, Error> {
+        self.optional_string(field)?
+            .ok_or_else(|| Error::new(self.path, Some(field), "is required"))
+    }
+
+    fn optional_string(&mut self, field: &str) -> Result<Option<String>, Error> {
+        match self.table.remove(field) {
+            None => Ok(None),
+            Some(Value::String(value)) if value.trim().is_empty() => {
+                Err(Error::new(self.path, Some(field), "must not be empty"))
+            }
+            Some(Value::String(value)) => Ok(Some(value)),
+            Some(other) => Err(self.mismatch(field, "a string", &other)),
+        }
+    }
+
+    /// A category path such as `hardware/riscv`, whose levels are separated by
+    /// `/` and can't be empty. Space around a level is dropped, so
+    /// `hardware / riscv` is `hardware/riscv`.
+    fn optional_category(&mut self, field: &str) -> Result<Option<String>, Error> {
+        let Some(path) = self.optional_string(field)? else {
+            return Ok(None);
+        };
+        let levels: Vec<&str> = path.split('/').map(str::trim).collect();
+        if levels.iter().any(|level| level.is_empty()) {
+            return Err(Error::new(
+                self.path,
+                Some(field),
+                format!("category \"{path}\" has an empty level"),
+            ));
+        }
+        Ok(Some(levels.join("/")))
+    }
+
+    /// A date such as `2021-01-01`, or a date and time such as
+    /// `2021-01-01T09:00:00+02:00`. A time without an offset is in UTC.
+    fn required_datetime(&mut self, field: &str) -> Result<(Date, Option<Time>), Error> {
+        const EXPECTED: &str = "a date such as 2021-01-01 or 2021-01-01T09:00:00Z";
+        match self.table.remove(field) {
+            None => Err(Error::new(self.path, Some(field), "is required")),
+            Some(Value::Datetime(datetime)) => {
+                let Some(date) = datetime.date else {
+                    return Err(Error::new(
+                        self.path,
+                        Some(field),
+                        format!("expected {EXPECTED}, found `{datetime}`"),
+                    ));
+                };
+                let date = Date {
+                    year: date.year,
+                    month: date.month,
+                    day: date.day,
+                };
+                let time = datetime.time.map(|time| Time {
+                    hour: time.hour,
+                    minute: time.minute,
+                    second: time.second,
+                    offset_minutes: match datetime.offset {
+                        Some(Offset::Custom { minutes }) => minutes,
+                        Some(Offset::Z) | None => 0,
+                    },
+                });
+                Ok((date, time))
+            }
+            Some(other) => Err(self.mismatch(field, EXPECTED, &other)),
+        }
+    }
+
+    /// One of the lifecycle stages of a post, `"published"` if it isn't given.
+    fn status(&mut self, field: &str) -> Result<Status, Error> {
+        let status = self.optional_string(field)?;
+        match status.as_deref() {
+            None | Some("published") => Ok(Status::Published),
+            Some("draft") => Ok(Status::Draft),
+            Some("unlisted") => Ok(Status::Unlisted),
+            Some("archived") => Ok(Status::Archived),
+            Some(other) => Err(Error::new(
+                self.path,
+                Some(field),
+                format!(
+                    "expected \"draft\", \"unlisted\", \"published\" or \"archived\", found \"{other}\""
+                ),
+            )),
+        }
+    }
+
+    fn optional_part(&mut self, field: &str) -> Result<Option<u32>, Error> {
+        match self.table.remove(field) {
+            None => Ok(None),
+            Some(Value::Integer(part)) => match u32::try_from(part) {
+                Ok(part) if part > 0 => Ok(Some(part)),
+                _ => Err(Error::new(
+                    self.path,
+                    Some(field),
+                    format!("expected a positive integer, found {part}"),
+                )),
+            },
+            Some(other) => Err(self.mismatch(field, "a positive integer", &other)),
+        }
+    }
+
+    /// A list of tags, each of which names its page and so is written in
+    /// lowercase letters, digits and `-`.
+    fn tags(&mut self, field: &str) -> Result<Vec<String>, Error> {
+        let tags = self.strings(field, "tag")?;
+        let invalid = tags.iter().find(|tag| {
+            !tag.chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        });
+        if let Some(tag) = invalid {
+            return Err(Error::new(
+                self.path,
+                Some(field),
+                format!("tag \"{tag}\" may only use lowercase letters, digits and `-`"),
+            ));
+        }
+        Ok(tags)
+    }
+
+    /// The ids of a post's authors, of which there has to be at least one.
+    fn required_authors(&mut self, field: &str) -> Result<Vec<String>, Error> {
+        let authors = self.strings(field, "author")?;
+        if authors.is_empty() {
+            return Err(Error::new(self.path, Some(field), "is required"));
+        }
+        Ok(authors)
+    }
+
+    /// An array of distinct, non-empty strings, each of which is a `what`.
+    fn strings(&mut self, field: &str, what: &str) -> Result<Vec<String>, Error> {
+        let expected = format!("an array of {what}s");
+        let values = match self.table.remove(field) {
+            None => return Ok(Vec::new()),
+            Some(Value::Array(values)) => values,
+            Some(other) => return Err(self.mismatch(field, &expected, &other)),
+        };
+
+        let mut strings: Vec<String> = Vec::new();
+        for value in values {
+            let string = match value {
+                Value::String(string) => string,
+                other => return Err(self.mismatch(field, &expected, &other)),
+            };
+            if string.trim().is_empty() {
+                return Err(Error::new(
+                    self.path,
+                    Some(field),
+                    format!("{what}s must not be empty"),
+                ));
+            }
+            if strings.contains(&string) {
+                return Err(Error::new(
+                    self.path,
+                    Some(field),
+                    format!("{what} \"{string}\" is listed twice"),
+                ));
+            }
+            strings.push(string);
+        }
+        Ok(strings)
+    }
+
+    /// Links such as `[{ label = "GitHub", url = "https://github.com/..." }]`,
+    /// as label and URL pairs in the order given.
+    fn links(&mut self, field: &str) -> Result<Vec<(String, String)>, Error> {
+        const EXPECTED: &str = "an array of `{ label = \"...\", url = \"...\" }` tables";
+        let values = match self.table.remove(field) {
+            None => return Ok(Vec::new()),
+            Some(Value::Array(values)) => values,
+            Some(other) => return Err(self.mismatch(field, EXPECTED, &other)),
+        };
+
+        let mut links = Vec::new();
+        for value in values {
+            let Value::Table(mut link) = value else {
+                return Err(self.mismatch(field, EXPECTED, &value));
+            };
+            let mut text = |key: &str| match link.remove(key) {
+                Some(Value::String(text)) if !text.trim().is_empty() => Ok(text),
+                _ => Err(Error::new(
+                    self.path,
+                    Some(field),
+                    format!("every link needs a non-empty `{key}`"),
+                )),
+            };
+            let label = text("label")?;
+            let url = text("url")?;
+            if let Some(unknown) = link.keys().next() {
+                return Err(Error::new(
+                    self.path,
+                    Some(field),
+                    format!("unknown link field `{unknown}`"),
+                ));
+            }
+            links.push((label, url));
+        }
+        Ok(links)
+    }
+
+    fn mismatch(&self, field: &str, expected: &str, found: &Value) -> Error {
+        Error::new(
+            self.path,
+            Some(field),
+            format!("expected {expected}, found {}", found.type_str()),
+        )
+    }
+}
@@ -0,0 +1,243 @@
+//! Build script: collects the posts in `assets/`, the series in
+//! `assets/series/` and the authors in `assets/authors/`, and generates the
+//! `POST_LIST` catalogue that `src/post.rs` includes, the `SERIES_LIST` that
+//! `src/series.rs` includes, the `AUTHOR_LIST` that `src/authors.rs` includes
+//! and the search index that `src/search.rs` includes.
+
+// word positions are only needed for snippets, not for the index
+#[allow(dead_code)]
+#[path = "../src/search/analyze.rs"]
+mod analyze;
+mod front_matter;
+#[path = "../src/markdown/links.rs"]
+mod links;
+mod related;
+mod search;
+mod validate;
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use front_matter::{AuthorSource, Error, PostSource, SeriesSource, Status, Time};
+
+const ASSETS_DIR: &str = "assets";
+const SERIES_DIR: &str = "assets/series";
+const AUTHORS_DIR: &str = "assets/authors";
+const ANALYZER: &str = "src/search/analyze.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed={ASSETS_DIR}");
+    println!("cargo:rerun-if-changed={ANALYZER}");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+
+    let posts = load(Path::new(ASSETS_DIR), front_matter::parse_post)
+        .unwrap_or_else(|errors| fail(&errors));
+    let series = load(Path::new(SERIES_DIR), front_matter::parse_series)
+        .unwrap_or_else(|errors| fail(&errors));
+    let authors = load(Path::new(AUTHORS_DIR), front_matter::parse_author)
+        .unwrap_or_else(|errors| fail(&errors));
+
+    let diagnostics = validate::validate(&posts, &series, &authors);
+    for warning in &diagnostics.warnings {
+        println!("cargo:warning={warning}");
+    }
+    if !diagnostics.errors.is_empty() {
+        fail(&diagnostics.errors);
+    }
+
+    // drafts are checked like any other post, but only debug builds show them
+    let mut posts = posts;
+    if env::var("PROFILE").as_deref() == Ok("release") {
+        posts.retain(|post| post.meta.status != Status::Draft);
+    }
+
+    let related = related::related(&posts);
+    let generated =
+        generate_post_list(&posts, &related, &out_dir).expect("failed to write post bodies");
+    fs::write(out_dir.join("posts.rs"), generated).expect("failed to write posts.rs");
+
+    let generated =
+        generate_series_list(&series, &out_dir).expect("failed to write series descriptions");
+    fs::write(out_dir.join("series.rs"), generated).expect("failed to write series.rs");
+
+    let generated = generate_author_list(&authors, &out_dir).expect("failed to write author bios");
+    fs::write(out_dir.join("authors.rs"), generated).expect("failed to write authors.rs");
+
+    let index = search::generate_index(&posts, &out_dir).expect("failed to write post texts");
+    fs::write(out_dir.join("search_index.rs"), index).expect("failed to write search_index.rs");
+}
+
+/// Reports every error and stops the build.
+fn fail(errors: &[String]) -> ! {
+    for error in errors {
+        eprintln!("error: {error}");
+    }
+    process::exit(1);
+}
+
+/// Reads and parses every `*.md` file in `dir`, sorted by file name. All
+/// malformed files are reported at once rather than stopping at the first.
+/// A missing directory has no files.
+fn load<T>(dir: &Path, parse: fn(&Path, &str) -> Result<T, Error>) -> Result<Vec<T>, Vec<String>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut paths = fs::read_dir(dir)
+        .map_err(|e| vec![format!("{}: {e}", dir.display())])?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let result = fs::read_to_string(&path)
+            .map_err(|e| format!("{}: {e}", path.display()))
+            .and_then(|source| parse(&path, &source).map_err(|e| e.to_string()));
+        match result {
+            Ok(item) => parsed.push(item),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Writes each post body (front matter stripped) next to the generated
+/// source and returns the `POST_LIST` expression that includes them.
+/// `related` has the related posts of each post, in the same order.
+fn generate_post_list(
+    posts: &[PostSource],
+    related: &[Vec<String>],
+    out_dir: &Path,
+) -> std::io::Result<String> {
+    let bodies = out_dir.join("posts");
+    fs::create_dir_all(&bodies)?;
+
+    let mut code = String::from("// @generated by build/main.rs from assets/*.md\n&[\n");
+    for (post, related) in posts.iter().zip(related) {
+        let body_path = bodies.join(format!("{}.md", post.id));
+        fs::write(&body_path, &post.body)?;
+
+        let meta = &post.meta;
+        let date = meta.published;
+        writeln!(code, "    Post {{").unwrap();
+        writeln!(code, "        id: {:?},", post.id).unwrap();
+        writeln!(code, "        meta: PostMetaData {{").unwrap();
+        writeln!(code, "            title: {:?},", meta.title).unwrap();
+        writeln!(code, "            authors: &{:?},", meta.authors).unwrap();
+        writeln!(
+            code,
+            "            published: date!({:04} - {:02} - {:02}),",
+            date.year, date.month, date.day
+        )
+        .unwrap();
+        let time = meta.published_time.unwrap_or(Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            offset_minutes: 0,
+        });
+        let sign = if time.offset_minutes < 0 { '-' } else { '+' };
+        let offset = time.offset_minutes.unsigned_abs();
+        writeln!(
+            code,
+            "            publish_at: datetime!({:04}-{:02}-{:02} {:02}:{:02}:{:02} {sign}{}:{:02}),",
+            date.year,
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second,
+            offset / 60,
+            offset % 60
+        )
+        .unwrap();
+        writeln!(code, "            status: Status::{:?},", meta.status).unwrap();
+        writeln!(code, "            category: {:?},", meta.category).unwrap();
+        writeln!(code, "            series: {:?},", meta.series).unwrap();
+        writeln!(code, "            part: {:?},", meta.part).unwrap();
+        writeln!(code, "            description: {:?},", meta.description).unwrap();
+        writeln!(code, "            tags: &{:?},", meta.tags).unwrap();
+        writeln!(code, "        }},").unwrap();
+        writeln!(code, "        content: include_str!({:?}),", body_path).unwrap();
+        writeln!(code, "        links: &{:?},", linked_posts(post)).unwrap();
+        writeln!(code, "        related: &{:?},", related).unwrap();
+        writeln!(code, "    }},").unwrap();
+    }
+    code.push_str("]\n");
+    Ok(code)
+}
+
+/// The ids of the other posts `post` links to, each once.
+fn linked_posts(post: &PostSource) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for (_, id) in links::post_links(&post.body) {
+        if id != post.id && !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Writes each series description next to the generated source and returns
+/// the `SERIES_LIST` expression that includes them.
+fn generate_series_list(series: &[SeriesSource], out_dir: &Path) -> std::io::Result<String> {
+    let descriptions = out_dir.join("series");
+    fs::create_dir_all(&descriptions)?;
+
+    let mut code = String::from("// @generated by build/main.rs from assets/series/*.md\n&[\n");
+    for series in series {
+        let description_path = descriptions.join(format!("{}.md", series.slug));
+        fs::write(&description_path, &series.description)?;
+
+        let meta = &series.meta;
+        writeln!(code, "    SeriesMetaData {{").unwrap();
+        writeln!(code, "        slug: {:?},", series.slug).unwrap();
+        writeln!(code, "        title: {:?},", meta.title).unwrap();
+        writeln!(code, "        status: SeriesStatus::{:?},", meta.status).unwrap();
+        writeln!(code, "        cover: {:?},", meta.cover).unwrap();
+        writeln!(
+            code,
+            "        description: include_str!({:?}),",
+            description_path
+        )
+        .unwrap();
+        writeln!(code, "    }},").unwrap();
+    }
+    code.push_str("]\n");
+    Ok(code)
+}
+
+/// Writes each author's bio next to the generated source and returns the
+/// `AUTHOR_LIST` expression that includes them.
+fn generate_author_list(authors: &[AuthorSource], out_dir: &Path) -> std::io::Result<String> {
+    let bios = out_dir.join("authors");
+    fs::create_dir_all(&bios)?;
+
+    let mut code = String::from("// @generated by build/main.rs from assets/authors/*.md\n&[\n");
+    for author in authors {
+        let bio_path = bios.join(format!("{}.md", author.id));
+        fs::write(&bio_path, &author.bio)?;
+
+        let meta = &author.meta;
+        writeln!(code, "    AuthorMetaData {{").unwrap();
+        writeln!(code, "        id: {:?},", author.id).unwrap();
+        writeln!(code, "        name: {:?},", meta.name).unwrap();
+        writeln!(code, "        avatar: {:?},", meta.avatar).unwrap();
+        writeln!(code, "        website: {:?},", meta.website).unwrap();
+        writeln!(code, "        links: &{:?},", meta.links).unwrap();
+        writeln!(code, "        bio: include_str!({:?}),", bio_path).unwrap();
+        writeln!(code, "    }},").unwrap();
+    }
+    code.push_str("]\n");
+    Ok(code)
+}
@@ -0,0 +1,128 @@
+//! The related posts of each post: every other post ranked by what the two
+//! share, so `src/related.rs` only has to skip the ones that aren't listed.
+
+use std::collections::BTreeMap;
+
+use crate::analyze::tokens;
+use crate::front_matter::PostSource;
+use crate::search::plain_text;
+
+/// Related posts kept for each post, more than are shown so that some can be
+/// left out when they aren't listed.
+const CANDIDATES: usize = 10;
+
+/// How much each thing two posts share adds to how related they are.
+const CATEGORY_WEIGHT: f32 = 2.0;
+const TAG_WEIGHT: f32 = 3.0;
+const AUTHOR_WEIGHT: f32 = 1.0;
+/// Multiplies the cosine similarity of the texts, which is between 0 and 1
+const TEXT_WEIGHT: f32 = 4.0;
+
+/// The ids of the posts most related to each of `posts`, best first. Posts
+/// of the same series are left out: the series navigation already links
+/// every part.
+pub fn related(posts: &[PostSource]) -> Vec<Vec<String>> {
+    let vectors = text_vectors(posts);
+    posts
+        .iter()
+        .zip(&vectors)
+        .map(|(post, vector)| {
+            let mut ranked: Vec<(f32, &PostSource)> = posts
+                .iter()
+                .zip(&vectors)
+                .filter(|(other, _)| other.id != post.id)
+                .filter(|(other, _)| {
+                    post.meta.series.is_none() || other.meta.series != post.meta.series
+                })
+                .map(|(other, other_vector)| {
+                    (
+                        score(post, other) + TEXT_WEIGHT * cosine(vector, other_vector),
+                        other,
+                    )
+                })
+                .filter(|(score, _)| *score > 0.0)
+                .collect();
+            // ties go to the newer post
+            ranked.sort_by(|(a, a_post), (b, b_post)| {
+                b.total_cmp(a)
+                    .then(b_post.meta.published.cmp(&a_post.meta.published))
+            });
+            ranked
+                .into_iter()
+                .take(CANDIDATES)
+                .map(|(_, other)| other.id.clone())
+                .collect()
+        })
+        .collect()
+}
+
+/// What `post` and `other` share besides their text: each level of category
+/// from the top, each tag and each author.
+fn score(post: &PostSource, other: &PostSource) -> f32 {
+    let levels = match (&post.meta.category, &other.meta.category) {
+        (Some(a), Some(b)) => a
+            .split('/')
+            .zip(b.split('/'))
+            .take_while(|(a, b)| a == b)
+            .count(),
+        _ => 0,
+    };
+    let shared = |a: &[String], b: &[String]| a.iter().filter(|value| b.contains(value)).count();
+    let tags = shared(&post.meta.tags, &other.meta.tags);
+    let authors = shared(&post.meta.authors, &other.meta.authors);
+    CATEGORY_WEIGHT * levels as f32 + TAG_WEIGHT * tags as f32 + AUTHOR_WEIGHT * authors as f32
+}
+
+/// The TF-IDF vector of each post's title and text, normalised to length 1,
+/// so words every post uses count for little. Terms are kept in order, so the
+/// sums over them, and the ranking, are the same on every build.
+fn text_vectors(posts: &[PostSource]) -> Vec<BTreeMap<String, f32>> {
+    let frequencies: Vec<BTreeMap<String, f32>> = posts
+        .iter()
+        .map(|post| {
+            let mut frequencies = BTreeMap::new();
+            let text = plain_text(&post.body);
+            for token in tokens(&post.meta.title).chain(tokens(&text)) {
+                *frequencies.entry(token.term).or_default() += 1.0;
+            }
+            frequencies
+        })
+        .collect();
+
+    let mut containing: BTreeMap<&str, f32> = BTreeMap::new();
+    for term in frequencies
+        .iter()
+        .flat_map(|frequencies| frequencies.keys())
+    {
+        *containing.entry(term).or_default() += 1.0;
+    }
+    let count = posts.len() as f32;
+    let vectors: Vec<BTreeMap<String, f32>> = frequencies
+        .iter()
+        .map(|frequencies| {
+            let mut vector: BTreeMap<String, f32> = frequencies
+                .iter()
+                .map(|(term, frequency)| {
+                    let idf = (count / containing[term.as_str()]).ln();
+                    (term.clone(), frequency * idf)
+                })
+                .collect();
+            let length = vector
+                .values()
+                .map(|weight| weight * weight)
+                .sum::<f32>()
+                .sqrt();
+            if length > 0.0 {
+                vector.values_mut().for_each(|weight| *weight /= length);
+            }
+            vector
+        })
+        .collect();
+    vectors
+}
+
+fn cosine(a: &BTreeMap<String, f32>, b: &BTreeMap<String, f32>) -> f32 {
+    a.iter()
+        .filter_map(|(term, weight)| Some(weight * b.get(term)?))
+        .sum()
+}
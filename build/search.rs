@@ -0,0 +1,98 @@
+//! The full-text search index `src/search.rs` includes: an inverted index from
+//! each term to the posts it occurs in, and the plain text of each post that
+//! result snippets are cut from.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use pulldown_cmark::{Event, Parser, Tag};
+
+use crate::analyze::tokens;
+use crate::front_matter::PostSource;
+use crate::links;
+
+/// How much more a term counts in each field than in the body.
+const TITLE_WEIGHT: u32 = 3;
+const DESCRIPTION_WEIGHT: u32 = 2;
+
+/// Writes each post's plain text next to the generated source and returns
+/// the `SearchIndex` expression. Posts are numbered in the order given, which
+/// is the order of `POST_LIST`.
+pub fn generate_index(posts: &[PostSource], out_dir: &Path) -> std::io::Result<String> {
+    let texts = out_dir.join("search");
+    fs::create_dir_all(&texts)?;
+
+    // term -> (post, weighted frequency)
+    let mut terms: BTreeMap<String, Vec<(usize, u32)>> = BTreeMap::new();
+    let mut lengths = Vec::new();
+    let mut text_paths = Vec::new();
+    for (number, post) in posts.iter().enumerate() {
+        let text = plain_text(&post.body);
+        let text_path = texts.join(format!("{}.txt", post.id));
+        fs::write(&text_path, &text)?;
+        text_paths.push(text_path);
+
+        let mut frequencies: BTreeMap<String, u32> = BTreeMap::new();
+        let fields = [
+            (post.meta.title.as_str(), TITLE_WEIGHT),
+            (
+                post.meta.description.as_deref().unwrap_or_default(),
+                DESCRIPTION_WEIGHT,
+            ),
+            (text.as_str(), 1),
+        ];
+        for (field, weight) in fields {
+            for token in tokens(field) {
+                *frequencies.entry(token.term).or_default() += weight;
+            }
+        }
+        lengths.push(frequencies.values().sum::<u32>());
+        for (term, frequency) in frequencies {
+            terms.entry(term).or_default().push((number, frequency));
+        }
+    }
+
+    let average_length = if lengths.is_empty() {
+        0.0
+    } else {
+        lengths.iter().sum::<u32>() as f32 / lengths.len() as f32
+    };
+
+    let mut code = String::from("// @generated by build/search.rs from assets/*.md\n");
+    code.push_str("SearchIndex {\n    text: &[\n");
+    for path in &text_paths {
+        writeln!(code, "        include_str!({path:?}),").unwrap();
+    }
+    writeln!(code, "    ],\n    lengths: &{lengths:?},").unwrap();
+    writeln!(code, "    average_length: {average_length:?},").unwrap();
+    code.push_str("    terms: &[\n");
+    for (term, postings) in &terms {
+        writeln!(code, "        ({term:?}, &{postings:?}),").unwrap();
+    }
+    code.push_str("    ],\n}\n");
+    Ok(code)
+}
+
+/// The words of a post's Markdown without its markup, a line per block.
+pub fn plain_text(markdown: &str) -> String {
+    let mut text = String::new();
+    for event in Parser::new_ext(markdown, links::options()) {
+        match event {
+            Event::Text(part) | Event::Code(part) => text.push_str(&part),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak | Event::End(Tag::Paragraph | Tag::Heading(..) | Tag::Item) => {
+                text.push('\n')
+            }
+            Event::End(Tag::TableCell) => text.push(' '),
+            Event::End(Tag::CodeBlock(_) | Tag::TableRow | Tag::TableHead)
+                if !text.ends_with('\n') =>
+            {
+                text.push('\n')
+            }
+            _ => {}
+        }
+    }
+    text
+}
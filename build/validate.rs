@@ -0,0 +1,254 @@
+//! Checks the invariants of the post catalogue that the front matter of a
+//! single file can't express on its own.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::front_matter::{AuthorSource, PostSource, SeriesSource, Status};
+use crate::links::post_links;
+
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+pub fn validate(
+    posts: &[PostSource],
+    series: &[SeriesSource],
+    authors: &[AuthorSource],
+) -> Diagnostics {
+    let mut diagnostics = Diagnostics::default();
+    check_ids(posts, &mut diagnostics);
+    check_content(posts, &mut diagnostics);
+    check_series(posts, &mut diagnostics);
+    check_series_registry(posts, series, &mut diagnostics);
+    check_authors(posts, authors, &mut diagnostics);
+    check_descriptions(posts, &mut diagnostics);
+    check_links(posts, series, authors, &mut diagnostics);
+    diagnostics
+}
+
+/// Pages of the blog at `/blog/<name>`, which a post can't be called.
+const RESERVED_IDS: &[&str] = &["archive", "tags"];
+
+/// No two posts may share an id, since the id is the post's URL.
+fn check_ids(posts: &[PostSource], diagnostics: &mut Diagnostics) {
+    let mut seen: HashMap<&str, &PostSource> = HashMap::new();
+    for post in posts {
+        if RESERVED_IDS.contains(&post.id.as_str()) {
+            diagnostics.errors.push(format!(
+                "{}: post id `{}` is taken by the page at /blog/{}",
+                post.path.display(),
+                post.id,
+                post.id
+            ));
+        }
+        if let Some(first) = seen.insert(&post.id, post) {
+            diagnostics.errors.push(format!(
+                "{}: duplicate post id `{}` (also used by {})",
+                post.path.display(),
+                post.id,
+                first.path.display()
+            ));
+        }
+    }
+}
+
+fn check_content(posts: &[PostSource], diagnostics: &mut Diagnostics) {
+    for post in posts.iter().filter(|post| post.body.trim().is_empty()) {
+        diagnostics
+            .errors
+            .push(format!("{}: post has no content", post.path.display()));
+    }
+}
+
+/// Every post in a series needs a part, and the parts of a series must run
+/// 1, 2, 3, ... with no gaps or repeats. A part without a series is an error.
+fn check_series(posts: &[PostSource], diagnostics: &mut Diagnostics) {
+    let mut series: BTreeMap<&str, Vec<&PostSource>> = BTreeMap::new();
+    for post in posts {
+        match (&post.meta.series, post.meta.part) {
+            (Some(name), _) => series.entry(name).or_default().push(post),
+            (None, Some(part)) => diagnostics.errors.push(format!(
+                "{}: field `part`: part {part} is set but the post has no `series`",
+                post.path.display()
+            )),
+            (None, None) => {}
+        }
+    }
+
+    for (name, mut parts) in series {
+        for post in parts.iter().filter(|post| post.meta.part.is_none()) {
+            diagnostics.errors.push(format!(
+                "{}: field `part`: post is in series \"{name}\" but has no part number",
+                post.path.display()
+            ));
+        }
+        parts.retain(|post| post.meta.part.is_some());
+        parts.sort_by_key(|post| post.meta.part);
+
+        for pair in parts.windows(2) {
+            if pair[0].meta.part == pair[1].meta.part {
+                diagnostics.errors.push(format!(
+                    "{}: field `part`: series \"{name}\" already has a part {} ({})",
+                    pair[1].path.display(),
+                    pair[1].meta.part.unwrap(),
+                    pair[0].path.display()
+                ));
+            }
+        }
+
+        let mut expected = 1;
+        for post in &parts {
+            let part = post.meta.part.unwrap();
+            if part > expected {
+                let missing = if part - expected == 1 {
+                    format!("part {expected} is")
+                } else {
+                    format!("parts {expected} to {} are", part - 1)
+                };
+                diagnostics
+                    .errors
+                    .push(format!("series \"{name}\": {missing} missing"));
+            }
+            expected = part + 1;
+        }
+
+        for pair in parts.windows(2) {
+            if pair[1].meta.published < pair[0].meta.published {
+                diagnostics.warnings.push(format!(
+                    "{}: part {} of series \"{name}\" is published before part {}",
+                    pair[1].path.display(),
+                    pair[1].meta.part.unwrap(),
+                    pair[0].meta.part.unwrap()
+                ));
+            }
+        }
+    }
+}
+
+/// Every series a post is in needs an entry in `assets/series/`, which gives
+/// its page, and no two entries may have the same title.
+fn check_series_registry(
+    posts: &[PostSource],
+    series: &[SeriesSource],
+    diagnostics: &mut Diagnostics,
+) {
+    let mut registered: HashMap<&str, &SeriesSource> = HashMap::new();
+    for entry in series {
+        if let Some(first) = registered.insert(&entry.meta.title, entry) {
+            diagnostics.errors.push(format!(
+                "{}: field `title`: series \"{}\" is already described by {}",
+                entry.path.display(),
+                entry.meta.title,
+                first.path.display()
+            ));
+        }
+    }
+
+    for post in posts {
+        if let Some(name) = &post.meta.series {
+            if !registered.contains_key(name.as_str()) {
+                diagnostics.errors.push(format!(
+                    "{}: field `series`: series \"{name}\" has no file in assets/series/",
+                    post.path.display()
+                ));
+            }
+        }
+    }
+
+    for entry in series {
+        let parts = posts
+            .iter()
+            .filter(|post| post.meta.series.as_ref() == Some(&entry.meta.title))
+            .count();
+        if parts == 0 {
+            diagnostics.warnings.push(format!(
+                "{}: series \"{}\" has no posts yet",
+                entry.path.display(),
+                entry.meta.title
+            ));
+        }
+    }
+}
+
+/// Every author of a post needs an entry in `assets/authors/`, which gives
+/// their name and page.
+fn check_authors(posts: &[PostSource], authors: &[AuthorSource], diagnostics: &mut Diagnostics) {
+    for post in posts {
+        for id in &post.meta.authors {
+            if !authors.iter().any(|author| &author.id == id) {
+                diagnostics.errors.push(format!(
+                    "{}: field `authors`: author `{id}` has no file in assets/authors/",
+                    post.path.display()
+                ));
+            }
+        }
+    }
+
+    for author in authors {
+        if !posts
+            .iter()
+            .any(|post| post.meta.authors.contains(&author.id))
+        {
+            diagnostics.warnings.push(format!(
+                "{}: author `{}` has no posts yet",
+                author.path.display(),
+                author.id
+            ));
+        }
+    }
+}
+
+fn check_descriptions(posts: &[PostSource], diagnostics: &mut Diagnostics) {
+    for post in posts.iter().filter(|post| post.meta.description.is_none()) {
+        diagnostics.warnings.push(format!(
+            "{}: post has no `description`; listings will only show its title",
+            post.path.display()
+        ));
+    }
+}
+
+/// A relative link to another post, from a post, a series description or an
+/// author's bio, must go to one that exists. Only a draft can link to a
+/// draft, since release builds leave drafts out.
+fn check_links(
+    posts: &[PostSource],
+    series: &[SeriesSource],
+    authors: &[AuthorSource],
+    diagnostics: &mut Diagnostics,
+) {
+    let statuses: HashMap<&str, Status> = posts
+        .iter()
+        .map(|post| (post.id.as_str(), post.meta.status))
+        .collect();
+
+    let documents = posts
+        .iter()
+        .map(|post| (&post.path, &post.body, post.meta.status == Status::Draft))
+        .chain(
+            series
+                .iter()
+                .map(|series| (&series.path, &series.description, false)),
+        )
+        .chain(
+            authors
+                .iter()
+                .map(|author| (&author.path, &author.bio, false)),
+        );
+    for (path, markdown, draft) in documents {
+        for (dest, id) in post_links(markdown) {
+            match statuses.get(id.as_str()) {
+                None => diagnostics.errors.push(format!(
+                    "{}: link `{dest}` goes to post `{id}`, which doesn't exist",
+                    path.display()
+                )),
+                Some(Status::Draft) if !draft => diagnostics.errors.push(format!(
+                    "{}: link `{dest}` goes to post `{id}`, which is a draft",
+                    path.display()
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! The archive: every post by the year and month it was published, with a
+//! page for each year and each month.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use time::Month;
+
+use crate::listing::{self, Sort};
+use crate::post::{listed_posts, Post};
+use crate::post_card::{CardLayout, PostCard};
+use crate::Route;
+
+/// The months of a year that have posts, newest first, each with its posts.
+type Months = Vec<(Month, Vec<&'static Post>)>;
+
+/// Listed posts published in `year`, or only in `month` of it, newest first.
+fn posts_in(year: Option<i32>, month: Option<Month>) -> Vec<&'static Post> {
+    let mut posts: Vec<&Post> = listed_posts()
+        .filter(|post| year.is_none_or(|year| post.meta.published.year() == year))
+        .filter(|post| month.is_none_or(|month| post.meta.published.month() == month))
+        .collect();
+    listing::sort(&mut posts, Sort::Newest, None);
+    posts
+}
+
+/// `posts`, which are newest first, grouped by year and then by month.
+fn group(posts: Vec<&'static Post>) -> Vec<(i32, Months)> {
+    let mut years: Vec<(i32, Months)> = Vec::new();
+    for post in posts {
+        let (year, month) = (post.meta.published.year(), post.meta.published.month());
+        if years.last().is_none_or(|(last, _)| *last != year) {
+            years.push((year, Vec::new()));
+        }
+        let (_, months) = years.last_mut().expect("a year was just pushed");
+        match months.last_mut() {
+            Some((last, posts)) if *last == month => posts.push(post),
+            _ => months.push((month, vec![post])),
+        }
+    }
+    years
+}
+
+/// Every year and month with posts, which the export writes a page for.
+#[cfg(feature = "ssr")]
+pub fn periods() -> Vec<(i32, Vec<Month>)> {
+    group(posts_in(None, None))
+        .into_iter()
+        .map(|(year, months)| (year, months.into_iter().map(|(month, _)| month).collect()))
+        .collect()
+}
+
+fn month_route(year: i32, month: Month) -> Route {
+    Route::ArchiveMonth {
+        year,
+        month: month as u8,
+    }
+}
+
+/// `1 post` or `3 posts`.
+fn count(count: usize) -> String {
+    if count == 1 {
+        "1 post".to_string()
+    } else {
+        format!("{count} posts")
+    }
+}
+
+#[component]
+pub fn Archive(cx: Scope) -> Element {
+    let years = group(posts_in(None, None));
+
+    render! {
+        div {
+            class: "bg-gray-200 dark:bg-gray-800 p-8",
+            p {
+                class: "dark:text-white mb-2",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::PostList {},
+                    u {
+                        "<< Back to Blog"
+                    }
+                }
+            }
+            h1 {
+                class: "text-3xl dark:text-white font-bold mb-4",
+                "Archive"
+            }
+            if years.is_empty() {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "No posts here yet :p"
+                    }
+                }
+            }
+            for (index, (year, months)) in years.into_iter().enumerate() {
+                details {
+                    class: "mb-4 dark:text-white",
+                    // only the latest year starts open, so older ones are a
+                    // click away rather than a long scroll
+                    open: index == 0,
+                    // the summary text toggles the section, so the page of
+                    // the period gets a link of its own next to it
+                    summary {
+                        class: "cursor-pointer text-2xl font-bold",
+                        "{year}"
+                        span {
+                            class: "ml-2 text-sm font-normal text-gray-500 dark:text-gray-400",
+                            count(months.iter().map(|(_, posts)| posts.len()).sum())
+                        }
+                        Link {
+                            class: "ml-2 text-sm font-normal text-blue-400 dark:text-orange-600",
+                            to: Route::ArchiveYear { year },
+                            u {
+                                "Open {year} >>"
+                            }
+                        }
+                    }
+                    for (month, posts) in months {
+                        details {
+                            class: "ml-4 mt-2",
+                            open: true,
+                            summary {
+                                class: "cursor-pointer text-xl font-bold",
+                                "{month}"
+                                span {
+                                    class: "ml-2 text-sm font-normal text-gray-500 dark:text-gray-400",
+                                    count(posts.len())
+                                }
+                                Link {
+                                    class: "ml-2 text-sm font-normal text-blue-400 dark:text-orange-600",
+                                    to: month_route(year, month),
+                                    u {
+                                        "Open {month} {year} >>"
+                                    }
+                                }
+                            }
+                            ul {
+                                class: "ml-4",
+                                for post in posts {
+                                    li {
+                                        span {
+                                            class: "text-gray-500 dark:text-gray-400",
+                                            "{post.meta.published} • "
+                                        }
+                                        Link {
+                                            class: "text-blue-400 dark:text-orange-600",
+                                            to: Route::Post { id: post.id.to_string() },
+                                            post.meta.title
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ArchiveYear(cx: Scope, year: i32) -> Element<'a> {
+    let months = group(posts_in(Some(*year), None))
+        .pop()
+        .map(|(_, months)| months)
+        .unwrap_or_default();
+
+    render! {
+        div {
+            class: "bg-gray-200 dark:bg-gray-800 p-8",
+            p {
+                class: "dark:text-white mb-2",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::Archive {},
+                    u {
+                        "<< Archive"
+                    }
+                }
+            }
+            h1 {
+                class: "text-3xl dark:text-white font-bold mb-4",
+                "{year}"
+            }
+            if months.is_empty() {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "No posts from {year}."
+                    }
+                }
+            }
+            for (month, posts) in months {
+                h2 {
+                    class: "text-2xl dark:text-white font-bold mb-4",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: month_route(*year, month),
+                        "{month}"
+                    }
+                    span {
+                        class: "ml-2 text-sm font-normal text-gray-500 dark:text-gray-400",
+                        count(posts.len())
+                    }
+                }
+                for post in posts {
+                    PostCard {
+                        post: post,
+                        layout: CardLayout::Compact,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ArchiveMonth(cx: Scope, year: i32, month: u8) -> Element<'a> {
+    let Ok(month) = Month::try_from(*month) else {
+        return render! {
+            div {
+                class: "bg-gray-200 dark:bg-gray-800 p-8",
+                p {
+                    class: "dark:text-white",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::Archive {},
+                        u {
+                            "<< Archive"
+                        }
+                    }
+                }
+                h1 {
+                    class: "text-3xl dark:text-white font-bold mb-4",
+                    "Month not found"
+                }
+                p {
+                    class: "dark:text-white",
+                    "Months go from 1 for January to 12 for December."
+                }
+            }
+        };
+    };
+    let posts = posts_in(Some(*year), Some(month));
+
+    render! {
+        div {
+            class: "bg-gray-200 dark:bg-gray-800 p-8",
+            p {
+                class: "dark:text-white mb-2",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::ArchiveYear { year: *year },
+                    u {
+                        "<< {year}"
+                    }
+                }
+            }
+            h1 {
+                class: "text-3xl dark:text-white font-bold mb-4",
+                "{month} {year}"
+            }
+            if posts.is_empty() {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "No posts from {month} {year}."
+                    }
+                }
+            }
+            for post in posts {
+                PostCard {
+                    post: post,
+                    series_badge: true,
+                }
+            }
+        }
+    }
+}
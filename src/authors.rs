@@ -0,0 +1,202 @@
+//! The people who write the posts, each with a page of their own.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::listing::{self, Sort};
+use crate::markdown::Markdown;
+use crate::post::{listed_posts, Post, PostQuerySegments};
+use crate::post_card::PostCard;
+use crate::Route;
+
+#[derive(Clone, Debug)]
+pub struct AuthorMetaData {
+    /// The file stem of their entry in `assets/authors/`, which posts list
+    /// them by and is their URL
+    pub id: &'static str,
+    pub name: &'static str,
+    pub avatar: Option<&'static str>,
+    pub website: Option<&'static str>,
+    /// Label and URL of each profile elsewhere
+    pub links: &'static [(&'static str, &'static str)],
+    pub bio: &'static str,
+}
+
+// generated by the build script from each file in assets/authors/
+// invariant (checked by build/validate.rs): every author of a post is listed
+pub const AUTHOR_LIST: &[AuthorMetaData] = include!(concat!(env!("OUT_DIR"), "/authors.rs"));
+
+pub fn find(id: &str) -> Option<&'static AuthorMetaData> {
+    AUTHOR_LIST.iter().find(|author| author.id == id)
+}
+
+/// The name of the author `id`, or the id itself if they aren't listed.
+pub fn name(id: &str) -> &str {
+    find(id).map_or(id, |author| author.name)
+}
+
+/// `Author 1, Author 2 and Author 3`, each linking to their page.
+#[component]
+pub fn AuthorLinks<'a>(cx: Scope<'a>, ids: &'a [&'static str]) -> Element<'a> {
+    render! {
+        for (index, id) in ids.iter().enumerate() {
+            if index + 1 == ids.len() && index > 0 {
+                rsx! { " and " }
+            } else if index > 0 {
+                rsx! { ", " }
+            }
+            Link {
+                class: "text-blue-400 dark:text-orange-600",
+                to: Route::Author { id: id.to_string() },
+                name(id)
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Author(cx: Scope, id: String) -> Element<'a> {
+    let author = find(id);
+
+    if let Some(author) = author {
+        let mut posts: Vec<&Post> = listed_posts()
+            .filter(|post| post.meta.authors.contains(&author.id))
+            .collect();
+        listing::sort(&mut posts, Sort::Newest, None);
+        let search = Route::PostQuery {
+            query_params: PostQuerySegments {
+                join: true,
+                author: vec![author.id.to_string()],
+                ..Default::default()
+            },
+        };
+
+        cx.render(rsx! {
+            div {
+                class: "bg-gray-200 dark:bg-gray-800 p-8",
+                p {
+                    class: "dark:text-white mb-2",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::PostList {},
+                        u {
+                            "<< Back to Blog"
+                        }
+                    }
+                }
+                div {
+                    class: "flex items-center gap-4 mb-4",
+                    if let Some(avatar) = author.avatar {
+                        rsx! {
+                            img {
+                                class: "w-24 h-24 rounded-full object-cover",
+                                src: "{avatar}",
+                                alt: "",
+                            }
+                        }
+                    }
+                    h1 {
+                        class: "text-4xl dark:text-white font-bold",
+                        author.name
+                    }
+                }
+                if !author.bio.trim().is_empty() {
+                    rsx! {
+                        div {
+                            class: "mb-4 dark:text-white",
+                            Markdown {
+                                content: author.bio
+                            }
+                        }
+                    }
+                }
+                if author.website.is_some() || !author.links.is_empty() {
+                    rsx! {
+                        ul {
+                            class: "flex flex-wrap gap-4 mb-4 dark:text-white",
+                            if let Some(website) = author.website {
+                                rsx! {
+                                    li {
+                                        a {
+                                            class: "text-blue-400 dark:text-orange-600",
+                                            href: "{website}",
+                                            rel: "me",
+                                            u {
+                                                "Website"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            for (label, url) in author.links.iter() {
+                                li {
+                                    a {
+                                        class: "text-blue-400 dark:text-orange-600",
+                                        href: "{url}",
+                                        rel: "me",
+                                        u {
+                                            "{label}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                h2 {
+                    class: "text-2xl dark:text-white font-bold mb-4",
+                    "Posts"
+                }
+                if posts.is_empty() {
+                    rsx! {
+                        p {
+                            class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                            "No posts here yet :p"
+                        }
+                    }
+                } else {
+                    rsx! {
+                        p {
+                            class: "dark:text-white mb-4",
+                            Link {
+                                class: "text-blue-400 dark:text-orange-600",
+                                to: search,
+                                "Search posts by {author.name}"
+                            }
+                        }
+                    }
+                }
+                for post in posts {
+                    PostCard {
+                        post: post,
+                        series_badge: true,
+                    }
+                }
+            }
+        })
+    } else {
+        cx.render(rsx! {
+            div {
+                class: "bg-gray-200 dark:bg-gray-800 p-8",
+                p {
+                    class: "dark:text-white",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::PostList {},
+                        u {
+                            "<< Back to Blog"
+                        }
+                    }
+                }
+                h1 {
+                    class: "text-3xl dark:text-white font-bold mb-4",
+                    "Author not found"
+                }
+                p {
+                    class: "dark:text-white",
+                    "The author you are looking for does not exist."
+                }
+            }
+        })
+    }
+}
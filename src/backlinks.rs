@@ -0,0 +1,55 @@
+//! Backlinks: the posts that link to a post, so links can be followed in
+//! both directions.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::listing::{self, Sort};
+use crate::post::{listed_posts, Post};
+use crate::Route;
+
+/// The listed posts that link to `post`, newest first.
+fn referencing(post: &Post) -> Vec<&'static Post> {
+    let mut posts: Vec<&Post> = listed_posts()
+        .filter(|other| other.links.contains(&post.id))
+        .collect();
+    listing::sort(&mut posts, Sort::Newest, None);
+    posts
+}
+
+/// The posts that link to `post`, if any do.
+#[component]
+pub fn ReferencedBy<'a>(cx: Scope<'a>, post: &'a Post) -> Element<'a> {
+    let posts = referencing(post);
+
+    render! {
+        if !posts.is_empty() {
+            rsx! {
+                nav {
+                    class: "mt-4 p-4 rounded bg-gray-200 dark:bg-gray-700 dark:text-white",
+                    "aria-label": "Referenced by",
+                    p {
+                        class: "font-bold mb-2",
+                        "Referenced by"
+                    }
+                    ul {
+                        class: "list-disc list-inside",
+                        for post in posts {
+                            li {
+                                Link {
+                                    class: "text-blue-400 dark:text-orange-600",
+                                    to: Route::Post { id: post.id.to_string() },
+                                    post.meta.title
+                                }
+                                span {
+                                    class: "text-sm text-gray-500 dark:text-gray-400",
+                                    " • {post.meta.published}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
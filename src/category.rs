@@ -0,0 +1,137 @@
+//! Categories are paths such as `hardware/riscv`, where a post in a child
+//! category is also in each of its parents.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::post::PostQuerySegments;
+use crate::Route;
+
+const SEPARATOR: char = '/';
+
+/// Each level of `path` from the top down, as its name and the path up to and
+/// including it: `hardware/riscv` has `("hardware", "hardware")` and
+/// `("riscv", "hardware/riscv")`.
+pub fn levels(path: &str) -> impl Iterator<Item = (&str, &str)> {
+    path.match_indices(SEPARATOR)
+        .map(|(end, _)| end)
+        .chain([path.len()])
+        .scan(0, move |start, end| {
+            let name = &path[*start..end];
+            *start = end + 1;
+            Some((name, &path[..end]))
+        })
+}
+
+/// `path` and every category above it, which a post in `path` is also in.
+pub fn ancestors(path: &str) -> impl Iterator<Item = &str> {
+    levels(path).map(|(_, path)| path)
+}
+
+/// How many categories are above `path`.
+pub fn depth(path: &str) -> usize {
+    path.matches(SEPARATOR).count()
+}
+
+/// The last level of `path`, e.g. `riscv` for `hardware/riscv`.
+pub fn name(path: &str) -> &str {
+    path.rsplit(SEPARATOR).next().unwrap_or(path)
+}
+
+/// `hardware › riscv`, with each level linking to `query` narrowed down to
+/// that category.
+#[component]
+pub fn Breadcrumbs<'a>(cx: Scope<'a>, path: &'a str, query: PostQuerySegments) -> Element<'a> {
+    let levels: Vec<(&str, Route)> = levels(path)
+        .map(|(name, path)| {
+            let route = Route::PostQuery {
+                query_params: PostQuerySegments {
+                    category: vec![path.to_string()],
+                    page: None,
+                    ..query.clone()
+                },
+            };
+            (name, route)
+        })
+        .collect();
+
+    render! {
+        for (index, (name, route)) in levels.into_iter().enumerate() {
+            if index > 0 {
+                rsx! { " › " }
+            }
+            Link {
+                class: "text-blue-400 dark:text-orange-600",
+                to: route,
+                name
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::post::{Post, PostMetaData, Status};
+
+    fn post_in(category: &'static str) -> Post {
+        Post {
+            id: "fixture",
+            meta: PostMetaData {
+                title: "Fixture",
+                authors: &[],
+                published: time::macros::date!(2021 - 01 - 01),
+                publish_at: time::macros::datetime!(2021-01-01 0:00 UTC),
+                status: Status::Published,
+                category: Some(category),
+                series: None,
+                part: None,
+                description: None,
+                tags: &[],
+            },
+            content: "",
+            links: &[],
+            related: &[],
+        }
+    }
+
+    fn query(category: &[&str], not_category: &[&str]) -> PostQuerySegments {
+        PostQuerySegments {
+            category: category.iter().map(|path| path.to_string()).collect(),
+            not_category: not_category.iter().map(|path| path.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn splits_paths_into_levels() {
+        let levels: Vec<_> = levels("hardware/riscv/vector").collect();
+        assert_eq!(
+            levels,
+            [
+                ("hardware", "hardware"),
+                ("riscv", "hardware/riscv"),
+                ("vector", "hardware/riscv/vector"),
+            ]
+        );
+        assert_eq!(depth("hardware/riscv/vector"), 2);
+        assert_eq!(name("hardware/riscv/vector"), "vector");
+        assert_eq!(ancestors("Trash").collect::<Vec<_>>(), ["Trash"]);
+        assert_eq!(depth("Trash"), 0);
+    }
+
+    #[test]
+    fn posts_are_in_every_parent_category() {
+        let post = post_in("hardware/riscv");
+        assert!(query(&["hardware"], &[]).matches(&post));
+        assert!(query(&["hardware/riscv"], &[]).matches(&post));
+        assert!(!query(&["hardware/riscv/vector"], &[]).matches(&post));
+        // a level is a whole name, not a prefix of one
+        assert!(!query(&["hard"], &[]).matches(&post));
+        assert!(!query(&["hardware/risc"], &[]).matches(&post));
+        assert!(!query(&["software"], &[]).matches(&post));
+
+        assert!(!query(&[], &["hardware"]).matches(&post));
+        assert!(query(&[], &["hardware/riscv/vector"]).matches(&post));
+    }
+}
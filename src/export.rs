@@ -0,0 +1,231 @@
+//! `website export [DIR]`: pre-renders every page of the site into `DIR`
+//! (`dist` by default, where `dx build` leaves the web bundle) so the whole
+//! thing can be hosted by any static file server.
+//!
+//! Each route is written to `<path>/index.html` from the bundle's
+//! `index.html`, and hydrates in the browser just like a page from the `ssr`
+//! server. The feeds are written next to them. Build with `--release`, since
+//! debug builds inject the hot reload script into every page, and with
+//! `SITE_URL` set to where the site will be served, which the feeds link to.
+//!
+//! Static servers ignore query strings, so pages of routes with a query, like
+//! `/search/` and `/blog/page/2`, are exported empty for the web bundle to
+//! render once it has loaded, whatever the query. Nor can they serve the feed
+//! of a search: build the bundle with `STATIC_EXPORT=1 dx build` to leave
+//! their links out.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use axum::http::Request;
+use dioxus_fullstack::prelude::*;
+use dioxus_router::prelude::{FromQuery, Routable, SegmentType};
+
+use crate::authors::AUTHOR_LIST;
+use crate::listing::{ListOptions, POSTS_PER_PAGE};
+use crate::post::{listed_posts, PostQuerySegments, POST_LIST};
+use crate::series::SERIES_LIST;
+use crate::{archive, feed, tags, App, Route, ATOM_FEED, RSS_FEED};
+
+/// Suffix of the copy of the bundle's `index.html` as `dx build` wrote it,
+/// kept because exporting the home page overwrites the original. It sits
+/// next to `DIR` rather than in it, so it isn't deployed with the site:
+/// `dist.template.html` for `dist`.
+const TEMPLATE_SUFFIX: &str = ".template.html";
+
+pub fn main(dir: Option<String>) {
+    let dir = PathBuf::from(dir.unwrap_or_else(|| "dist".to_string()));
+    if let Err(error) = export(&dir) {
+        eprintln!("export failed: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn export(dir: &Path) -> Result<(), String> {
+    if option_env!("SITE_URL").is_none() {
+        return Err("build with SITE_URL set, or the feeds will link to localhost".to_string());
+    }
+    let template = template(dir)?;
+    let template_html = fs::read_to_string(&template)
+        .map_err(|error| format!("{}: {error}", template.display()))?;
+    // the serve config only takes a `&'static str`, and we only build it once
+    let template: &'static str = Box::leak(template.to_string_lossy().into_owned().into());
+    let cfg = ServeConfigBuilder::new(App, ())
+        .index_path(template)
+        .build();
+    let state = SSRState::new(&cfg);
+
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        for route in routes() {
+            let path = route.to_string();
+            let (parts, ()) = Request::builder()
+                .uri(&path)
+                .body(())
+                .map_err(|error| format!("{path}: {error}"))?
+                .into_parts();
+            let context = DioxusServerContext::new(Arc::new(RwLock::new(parts)));
+            let response = state
+                .render(path.clone(), &cfg, &context)
+                .await
+                .map_err(|error| format!("{path}: {error}"))?;
+            // a static server gives every query of a route the same file, so
+            // a page rendered for one of them wouldn't hydrate for the others
+            let html = if path.contains('?') {
+                client_rendered(&template_html, response.html())
+            } else {
+                response.html().to_string()
+            };
+            write(&page_file(dir, &path), &html)?;
+        }
+        Ok::<_, String>(())
+    })?;
+
+    let posts = feed::for_query(RSS_FEED, None);
+    write(&dir.join(&RSS_FEED[1..]), &feed::rss(&posts))?;
+    let posts = feed::for_query(ATOM_FEED, None);
+    write(&dir.join(&ATOM_FEED[1..]), &feed::atom(&posts))?;
+    Ok(())
+}
+
+/// Every page worth exporting: a page for each route of the site, expanded
+/// by [`pages`] where it has parameters.
+fn routes() -> Vec<Route> {
+    Route::flatten_site_map()
+        .filter_map(|segments| {
+            // any value does for the parameters, `pages` only looks at the
+            // variant
+            let path: String = segments
+                .iter()
+                .map(|segment| match segment {
+                    SegmentType::Dynamic(_) | SegmentType::CatchAll(_) => "/1".to_string(),
+                    segment => segment.to_string(),
+                })
+                .collect();
+            path.parse().ok()
+        })
+        .flat_map(pages)
+        .collect()
+}
+
+/// The pages exported for routes of the same variant as `route`: the route
+/// itself, or for one with parameters, each value the site links to.
+fn pages(route: Route) -> Vec<Route> {
+    match route {
+        Route::Home {}
+        | Route::About {}
+        | Route::Resume {}
+        | Route::PostList {}
+        | Route::Tags {}
+        | Route::Archive {} => vec![route],
+        Route::PostListPage { .. } => {
+            let pages = listed_posts().count().div_ceil(POSTS_PER_PAGE);
+            (2..=pages)
+                .map(|page| Route::PostListPage {
+                    page,
+                    options: ListOptions::default(),
+                })
+                .collect()
+        }
+        Route::Tag { .. } => tags::counts()
+            .into_iter()
+            .map(|(tag, _)| Route::Tag {
+                tag: tag.to_string(),
+            })
+            .collect(),
+        Route::ArchiveYear { .. } => archive::periods()
+            .into_iter()
+            .map(|(year, _)| Route::ArchiveYear { year })
+            .collect(),
+        Route::ArchiveMonth { .. } => archive::periods()
+            .into_iter()
+            .flat_map(|(year, months)| {
+                months.into_iter().map(move |month| Route::ArchiveMonth {
+                    year,
+                    month: month as u8,
+                })
+            })
+            .collect(),
+        // unlisted posts are exported too, so their links work; scheduled
+        // ones wait for an export after they go out
+        Route::Post { .. } => POST_LIST
+            .iter()
+            .filter(|post| post.is_released())
+            .map(|post| Route::Post {
+                id: post.id.to_string(),
+            })
+            .collect(),
+        Route::Series { .. } => SERIES_LIST
+            .iter()
+            .map(|series| Route::Series {
+                slug: series.slug.to_string(),
+            })
+            .collect(),
+        Route::Author { .. } => AUTHOR_LIST
+            .iter()
+            .map(|author| Route::Author {
+                id: author.id.to_string(),
+            })
+            .collect(),
+        Route::PostQuery { .. } => vec![Route::PostQuery {
+            query_params: PostQuerySegments::from_query(""),
+        }],
+    }
+}
+
+/// Finds the page template, refreshing the saved copy if `dx build` has
+/// written a new `index.html` since the last export.
+fn template(dir: &Path) -> Result<PathBuf, String> {
+    let index = dir.join("index.html");
+    let name = dir
+        .file_name()
+        .ok_or_else(|| format!("{}: not a directory name", dir.display()))?;
+    let saved = dir.with_file_name(format!("{}{TEMPLATE_SUFFIX}", name.to_string_lossy()));
+    let html = fs::read_to_string(&index)
+        .map_err(|error| format!("{}: {error}; run `dx build` first", index.display()))?;
+    if html.contains("id=\"main\"></div>") {
+        write(&saved, &html)?;
+    } else if !saved.exists() {
+        return Err(format!(
+            "{} is already pre-rendered and {} is missing; run `dx build` again",
+            index.display(),
+            saved.display()
+        ));
+    }
+    Ok(saved)
+}
+
+/// A page the web bundle renders from scratch: the template with nothing in
+/// it but the serialized state the bundle reads on load, taken from `html`,
+/// the same page rendered by the server.
+fn client_rendered(template: &str, html: &str) -> String {
+    const STORAGE: &str = "<meta hidden=\"true\" id=\"dioxus-storage";
+    let storage: String = html
+        .match_indices(STORAGE)
+        .filter_map(|(start, _)| {
+            let end = start + html[start..].find("/>")? + 2;
+            Some(&html[start..end])
+        })
+        .collect();
+    template.replacen(
+        "id=\"main\"></div>",
+        &format!("id=\"main\">{storage}</div>"),
+        1,
+    )
+}
+
+/// `/blog/post-1` is written to `blog/post-1/index.html`, and `/` to
+/// `index.html`.
+fn page_file(dir: &Path, path: &str) -> PathBuf {
+    let path = path.split('?').next().unwrap_or_default();
+    dir.join(path.trim_matches('/')).join("index.html")
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| format!("{}: {error}", parent.display()))?;
+    }
+    fs::write(path, contents).map_err(|error| format!("{}: {error}", path.display()))?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! The filter sidebar of the search page: every category, series, author and
+//! tag with a checkbox to add it to or drop it from the query.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::authors;
+use crate::category;
+use crate::post::{listed_posts, Post, PostQuerySegments};
+use crate::Route;
+
+#[derive(Clone, Copy)]
+enum Field {
+    Category,
+    Series,
+    Author,
+    Tag,
+}
+
+impl Field {
+    const ALL: [Self; 4] = [Self::Category, Self::Series, Self::Author, Self::Tag];
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Category => "Category",
+            Self::Series => "Series",
+            Self::Author => "Author",
+            Self::Tag => "Tag",
+        }
+    }
+
+    fn of(self, post: &Post) -> Vec<&'static str> {
+        match self {
+            Self::Category => post
+                .meta
+                .category
+                .into_iter()
+                .flat_map(category::ancestors)
+                .collect(),
+            Self::Series => post.meta.series.into_iter().collect(),
+            Self::Author => post.meta.authors.to_vec(),
+            Self::Tag => post.meta.tags.to_vec(),
+        }
+    }
+
+    fn values(self, query: &mut PostQuerySegments) -> &mut Vec<String> {
+        match self {
+            Self::Category => &mut query.category,
+            Self::Series => &mut query.series,
+            Self::Author => &mut query.author,
+            Self::Tag => &mut query.tag,
+        }
+    }
+}
+
+struct Facet {
+    value: &'static str,
+    /// What the value is shown as: the last level of a category, or the
+    /// name of an author
+    label: &'static str,
+    /// How far to indent it: the number of categories above it
+    depth: usize,
+    checked: bool,
+    /// Posts the value matches under the rest of the query
+    count: usize,
+    /// The query with the value toggled
+    toggled: Route,
+}
+
+/// One facet per value of `field` any post has, in alphabetical order (of
+/// their names, for authors) with each category followed by the ones below
+/// it.
+fn facets(field: Field, query: &PostQuerySegments) -> Vec<Facet> {
+    let mut values: Vec<&str> = listed_posts().flat_map(|post| field.of(post)).collect();
+    values.sort_unstable_by(|a, b| match field {
+        // by what readers see, with the id to keep each author's posts
+        // together for `dedup`
+        Field::Author => (authors::name(a), a).cmp(&(authors::name(b), b)),
+        _ => category::levels(a).cmp(category::levels(b)),
+    });
+    values.dedup();
+
+    // With `join` a value narrows down the other fields; without it, it adds
+    // its posts to theirs, so only the exclusions, dates and text apply.
+    let mut rest = query.clone();
+    if query.join {
+        field.values(&mut rest).clear();
+    } else {
+        for other in Field::ALL {
+            other.values(&mut rest).clear();
+        }
+    }
+
+    values
+        .into_iter()
+        .map(|value| {
+            let mut alone = rest.clone();
+            *field.values(&mut alone) = vec![value.to_string()];
+            let count = listed_posts().filter(|post| alone.matches(post)).count();
+
+            let mut toggled = PostQuerySegments {
+                page: None,
+                ..query.clone()
+            };
+            let values = field.values(&mut toggled);
+            let checked = values.iter().any(|v| v == value);
+            if checked {
+                values.retain(|v| v != value);
+            } else {
+                values.push(value.to_string());
+            }
+
+            let (label, depth) = match field {
+                Field::Category => (category::name(value), category::depth(value)),
+                Field::Author => (authors::name(value), 0),
+                _ => (value, 0),
+            };
+            Facet {
+                value,
+                label,
+                depth,
+                checked,
+                count,
+                toggled: Route::PostQuery {
+                    query_params: toggled,
+                },
+            }
+        })
+        .collect()
+}
+
+#[component]
+pub fn Facets(cx: Scope, query_params: PostQuerySegments) -> Element<'a> {
+    let navigator = use_navigator(cx);
+    let join = |join: bool| Route::PostQuery {
+        query_params: PostQuerySegments {
+            join,
+            page: None,
+            ..query_params.clone()
+        },
+    };
+    let all = join(true);
+    let any = join(false);
+
+    render! {
+        aside {
+            class: "md:w-64 shrink-0 dark:text-white",
+            h2 {
+                class: "text-xl font-bold mb-2",
+                "Filter"
+            }
+            fieldset {
+                class: "mb-4",
+                legend {
+                    class: "font-bold mb-1",
+                    "Show posts matching"
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "radio",
+                        name: "join",
+                        checked: query_params.join,
+                        onchange: move |_| {
+                            navigator.push(all.clone());
+                        },
+                    }
+                    "every field"
+                }
+                label {
+                    class: "flex items-center gap-2",
+                    input {
+                        r#type: "radio",
+                        name: "join",
+                        checked: !query_params.join,
+                        onchange: move |_| {
+                            navigator.push(any.clone());
+                        },
+                    }
+                    "any field"
+                }
+            }
+            for field in Field::ALL {
+                fieldset {
+                    class: "mb-4",
+                    legend {
+                        class: "font-bold mb-1",
+                        field.title()
+                    }
+                    for facet in facets(field, query_params) {
+                        label {
+                            class: if facet.count == 0 && !facet.checked {
+                                "flex items-center gap-2 opacity-50"
+                            } else {
+                                "flex items-center gap-2"
+                            },
+                            input {
+                                r#type: "checkbox",
+                                checked: facet.checked,
+                                onchange: move |_| {
+                                    navigator.push(facet.toggled.clone());
+                                },
+                            }
+                            span {
+                                class: "flex-grow",
+                                style: "padding-left: {facet.depth}rem",
+                                title: "{facet.value}",
+                                facet.label
+                            }
+                            span {
+                                class: "text-sm text-gray-500 dark:text-gray-400",
+                                "{facet.count}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
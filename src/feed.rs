@@ -0,0 +1,381 @@
+//! RSS 2.0 and Atom feeds of the blog, served by the `ssr` server.
+
+use std::fmt::Write;
+
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::authors;
+use crate::markdown::{render_html, MarkdownTheme};
+use crate::post::{listed_posts, Post, PostQuerySegments};
+use crate::{Route, ATOM_FEED, RSS_FEED};
+
+/// Absolute URL of the site, used for the links inside the feeds, from
+/// `SITE_URL` at build time. Debug builds fall back to the local server, but
+/// a release build without it fails rather than publish feeds that link to
+/// localhost.
+const SITE_URL: &str = match option_env!("SITE_URL") {
+    Some(url) => url,
+    None if cfg!(debug_assertions) => "http://localhost:8080",
+    None => panic!("set SITE_URL to the address the site is served from"),
+};
+const SITE_TITLE: &str = "Alessandra Simmons";
+/// Entry ids are built from the post id alone, so they survive changes to
+/// the title, the date or the domain the site is served from.
+const ID_PREFIX: &str = "urn:alessandra-simmons:post:";
+
+pub struct Feed<'a> {
+    pub title: String,
+    /// Path of the page the feed mirrors, e.g. `/blog/`
+    pub page_path: String,
+    /// Path the feed itself is served from, e.g. `/feed.xml`
+    pub self_path: String,
+    pub posts: Vec<&'a Post>,
+}
+
+impl Feed<'_> {
+    /// When the newest post went out, or the epoch for an empty feed.
+    fn updated(&self) -> OffsetDateTime {
+        self.posts
+            .iter()
+            .map(|post| post.meta.publish_at)
+            .max()
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+pub fn rss(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+    xml.push_str("<channel>\n");
+    element(&mut xml, "title", &feed.title);
+    element(&mut xml, "link", &url(&feed.page_path));
+    element(&mut xml, "description", &feed.title);
+    writeln!(
+        xml,
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>",
+        escape(&url(&feed.self_path))
+    )
+    .unwrap();
+    element(&mut xml, "lastBuildDate", &rfc2822(feed.updated()));
+
+    for post in &feed.posts {
+        xml.push_str("<item>\n");
+        element(&mut xml, "title", post.meta.title);
+        element(&mut xml, "link", &post_url(post));
+        writeln!(
+            xml,
+            "<guid isPermaLink=\"false\">{ID_PREFIX}{}</guid>",
+            escape(post.id)
+        )
+        .unwrap();
+        for id in post.meta.authors {
+            element(&mut xml, "dc:creator", authors::name(id));
+        }
+        element(&mut xml, "pubDate", &rfc2822(post.meta.publish_at));
+        for category in categories(post) {
+            element(&mut xml, "category", category);
+        }
+        if let Some(description) = post.meta.description {
+            element(&mut xml, "description", description);
+        }
+        element(&mut xml, "content:encoded", &content(post));
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+pub fn atom(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    element(&mut xml, "title", &feed.title);
+    element(&mut xml, "id", &url(&feed.self_path));
+    writeln!(
+        xml,
+        "<link href=\"{}\" rel=\"self\" type=\"application/atom+xml\"/>",
+        escape(&url(&feed.self_path))
+    )
+    .unwrap();
+    writeln!(
+        xml,
+        "<link href=\"{}\" rel=\"alternate\" type=\"text/html\"/>",
+        escape(&url(&feed.page_path))
+    )
+    .unwrap();
+    element(&mut xml, "updated", &rfc3339(feed.updated()));
+    xml.push_str("<author><name>");
+    xml.push_str(&escape(SITE_TITLE));
+    xml.push_str("</name></author>\n");
+
+    for post in &feed.posts {
+        let published = rfc3339(post.meta.publish_at);
+        xml.push_str("<entry>\n");
+        element(&mut xml, "title", post.meta.title);
+        writeln!(xml, "<id>{ID_PREFIX}{}</id>", escape(post.id)).unwrap();
+        writeln!(
+            xml,
+            "<link href=\"{}\" rel=\"alternate\" type=\"text/html\"/>",
+            escape(&post_url(post))
+        )
+        .unwrap();
+        element(&mut xml, "published", &published);
+        element(&mut xml, "updated", &published);
+        for id in post.meta.authors {
+            xml.push_str("<author><name>");
+            xml.push_str(&escape(authors::name(id)));
+            xml.push_str("</name></author>\n");
+        }
+        for category in categories(post) {
+            writeln!(xml, "<category term=\"{}\"/>", escape(category)).unwrap();
+        }
+        if let Some(description) = post.meta.description {
+            element(&mut xml, "summary", description);
+        }
+        writeln!(
+            xml,
+            "<content type=\"html\">{}</content>",
+            escape(&content(post))
+        )
+        .unwrap();
+        xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// The feed served at `self_path` for a request with the given query string:
+/// the whole blog without one, or the results of the equivalent
+/// [`Route::PostQuery`] search with one. Posts are newest first.
+pub fn for_query(self_path: &str, query: Option<&str>) -> Feed<'static> {
+    let mut feed = match query.filter(|query| !query.is_empty()) {
+        None => Feed {
+            title: SITE_TITLE.to_string(),
+            page_path: Route::PostList {}.to_string(),
+            self_path: self_path.to_string(),
+            posts: listed_posts().collect(),
+        },
+        Some(query) => {
+            let query_params = PostQuerySegments::from_raw_query(query);
+            Feed {
+                title: format!("{SITE_TITLE}: {}", describe(&query_params)),
+                self_path: format!("{self_path}?{query_params}"),
+                posts: listed_posts()
+                    .filter(|post| query_params.matches(post))
+                    .collect(),
+                page_path: Route::PostQuery { query_params }.to_string(),
+            }
+        }
+    };
+    feed.posts
+        .sort_by_key(|post| std::cmp::Reverse(post.meta.publish_at));
+    feed
+}
+
+/// A feed a page advertises, as a `<link rel="alternate">` in its head or a
+/// `Link` header on its response.
+pub struct Alternate {
+    pub content_type: &'static str,
+    pub title: &'static str,
+    pub path: String,
+}
+
+impl Alternate {
+    /// `</feed.xml?join=false>; rel="alternate"; type="application/rss+xml"; title="…"`
+    pub fn header(&self) -> String {
+        format!(
+            "<{}>; rel=\"alternate\"; type=\"{}\"; title=\"{}\"",
+            self.path, self.content_type, self.title
+        )
+    }
+
+    pub fn html(&self) -> String {
+        format!(
+            "<link rel=\"alternate\" type=\"{}\" title=\"{}\" href=\"{}\">",
+            self.content_type,
+            escape(self.title),
+            escape(&self.path)
+        )
+    }
+}
+
+/// `html` with `alternates` linked from its `<head>`.
+pub fn advertise(html: &str, alternates: &[Alternate]) -> String {
+    let links: String = alternates.iter().map(Alternate::html).collect();
+    html.replacen("</head>", &format!("{links}</head>"), 1)
+}
+
+/// The feeds advertised by the page at `route`: search pages have feeds of
+/// their results.
+pub fn alternates(route: &Route) -> Vec<Alternate> {
+    let Route::PostQuery { query_params } = route else {
+        return Vec::new();
+    };
+    vec![
+        Alternate {
+            content_type: "application/rss+xml",
+            title: "Search results (RSS)",
+            path: query_params.feed_path(RSS_FEED),
+        },
+        Alternate {
+            content_type: "application/atom+xml",
+            title: "Search results (Atom)",
+            path: query_params.feed_path(ATOM_FEED),
+        },
+    ]
+}
+
+/// A title for a search, e.g. `posts in series "Test Series" by Author 1`.
+fn describe(query: &PostQuerySegments) -> String {
+    let mut included = Vec::new();
+    if !query.category.is_empty() {
+        included.push(format!("in category {}", quoted(&query.category)));
+    }
+    if !query.series.is_empty() {
+        included.push(format!("in series {}", quoted(&query.series)));
+    }
+    if !query.author.is_empty() {
+        included.push(format!("by {}", names(&query.author)));
+    }
+    if !query.tag.is_empty() {
+        included.push(format!("tagged {}", tags(&query.tag)));
+    }
+    let separator = if query.join { " " } else { " or " };
+    let mut parts = vec![included.join(separator)];
+
+    if !query.not_category.is_empty() {
+        parts.push(format!("not in category {}", quoted(&query.not_category)));
+    }
+    if !query.not_series.is_empty() {
+        parts.push(format!("not in series {}", quoted(&query.not_series)));
+    }
+    if !query.not_author.is_empty() {
+        parts.push(format!("not by {}", names(&query.not_author)));
+    }
+    if !query.not_tag.is_empty() {
+        parts.push(format!("not tagged {}", tags(&query.not_tag)));
+    }
+    if let Some(after) = query.after {
+        parts.push(format!("from {after}"));
+    }
+    if let Some(before) = query.before {
+        parts.push(format!("until {before}"));
+    }
+    if let Some(q) = &query.q {
+        parts.push(format!("matching \"{q}\""));
+    }
+    parts.retain(|part| !part.is_empty());
+    if parts.is_empty() {
+        "all posts".to_string()
+    } else {
+        format!("posts {}", parts.join(" "))
+    }
+}
+
+/// `"Garbage" or "Trash"`
+fn quoted(values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|value| format!("\"{value}\"")).collect();
+    values.join(" or ")
+}
+
+/// `Author 1 or Author 2`, from author ids
+fn names(ids: &[String]) -> String {
+    let names: Vec<&str> = ids.iter().map(|id| authors::name(id)).collect();
+    names.join(" or ")
+}
+
+/// `#rust or #web-dev`
+fn tags(values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|value| format!("#{value}")).collect();
+    values.join(" or ")
+}
+
+fn element(xml: &mut String, name: &str, text: &str) {
+    writeln!(xml, "<{name}>{}</{name}>", escape(text)).unwrap();
+}
+
+/// The category of a post followed by its tags, which feeds don't tell apart.
+fn categories(post: &Post) -> impl Iterator<Item = &'static str> {
+    post.meta
+        .category
+        .into_iter()
+        .chain(post.meta.tags.iter().copied())
+}
+
+fn content(post: &Post) -> String {
+    render_html(post.content, &MarkdownTheme::PLAIN)
+}
+
+/// `at` in UTC, as RSS wants it: `Fri, 01 Jan 2021 09:00:00 +0000`
+fn rfc2822(at: OffsetDateTime) -> String {
+    let at = at.to_offset(UtcOffset::UTC);
+    let weekday = at.weekday().to_string();
+    let month = at.month().to_string();
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} +0000",
+        &weekday[..3],
+        at.day(),
+        &month[..3],
+        at.year(),
+        at.hour(),
+        at.minute(),
+        at.second()
+    )
+}
+
+/// `at` in UTC, as Atom wants it: `2021-01-01T09:00:00Z`
+fn rfc3339(at: OffsetDateTime) -> String {
+    let at = at.to_offset(UtcOffset::UTC);
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        at.date(),
+        at.hour(),
+        at.minute(),
+        at.second()
+    )
+}
+
+fn url(path: &str) -> String {
+    format!("{}{path}", SITE_URL.trim_end_matches('/'))
+}
+
+fn post_url(post: &Post) -> String {
+    url(&Route::Post {
+        id: post.id.to_string(),
+    }
+    .to_string())
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_the_publish_time_in_utc() {
+        let at = time::macros::datetime!(2021-01-01 09:30:05 +02:00);
+        assert_eq!(rfc2822(at), "Fri, 01 Jan 2021 07:30:05 +0000");
+        assert_eq!(rfc3339(at), "2021-01-01T07:30:05Z");
+
+        let at = time::macros::datetime!(2021-01-01 01:00 +02:00);
+        assert_eq!(rfc2822(at), "Thu, 31 Dec 2020 23:00:00 +0000");
+        assert_eq!(rfc3339(at), "2020-12-31T23:00:00Z");
+    }
+}
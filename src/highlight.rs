@@ -0,0 +1,588 @@
+//! A small syntax highlighter for fenced code blocks.
+//!
+//! It emits the same `hljs-*` class names as highlight.js, so the
+//! atom-one-dark stylesheet in `public/hljs/styles` colours the output
+//! without any script running in the browser.
+
+mod languages;
+
+use pulldown_cmark::escape::escape_html;
+
+use languages::find_language;
+
+/// How a language marks up metadata such as preprocessor lines or attributes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Meta {
+    None,
+    /// `#include <stdio.h>`: a `#` at the start of a line, to the end of it
+    Preprocessor,
+    /// `#[derive(Debug)]`: a `#[` or `#![` up to its matching `]`
+    Attribute,
+    /// `@decorator`: a prefix followed by an identifier
+    Prefixed(char),
+}
+
+/// The lexical rules of one language. Languages override the fields they
+/// need on top of [`Language::DEFAULT`].
+#[derive(Clone, Copy, Debug)]
+struct Language {
+    /// Fence info strings that select this language
+    names: &'static [&'static str],
+    case_insensitive: bool,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// `"""` and `'''` strings
+    triple_quotes: bool,
+    /// `r"..."` and `r#"..."#` strings
+    raw_strings: bool,
+    /// `'a` lifetimes alongside `'x'` character literals
+    lifetimes: bool,
+    /// `$name`, `${name}` and `$(name)`
+    variables: bool,
+    /// `println!` and friends
+    macros: bool,
+    /// `4'b1010` style sized literals
+    sized_numbers: bool,
+    meta: Meta,
+    /// Characters, besides ASCII letters and `_`, that can start an identifier
+    ident_start: &'static [char],
+    /// Characters, besides ASCII alphanumerics and `_`, allowed after the start
+    ident_continue: &'static [char],
+    /// Class of a `name:` at the start of a line, for assembly labels and
+    /// make targets
+    labels: Option<&'static str>,
+    keywords: &'static [&'static str],
+    built_ins: &'static [&'static str],
+    types: &'static [&'static str],
+    literals: &'static [&'static str],
+    /// Identifiers listed as `meta` (assembler directives)
+    directives: &'static [&'static str],
+    /// Keywords whose following identifier is a title, and the title's class
+    titles: &'static [(&'static str, &'static str)],
+}
+
+impl Language {
+    const DEFAULT: Self = Self {
+        names: &[],
+        case_insensitive: false,
+        line_comments: &[],
+        block_comment: None,
+        quotes: &['"'],
+        triple_quotes: false,
+        raw_strings: false,
+        lifetimes: false,
+        variables: false,
+        macros: false,
+        sized_numbers: false,
+        meta: Meta::None,
+        ident_start: &[],
+        ident_continue: &[],
+        labels: None,
+        keywords: &[],
+        built_ins: &[],
+        types: &[],
+        literals: &[],
+        directives: &[],
+        titles: &[],
+    };
+
+    fn is_ident_start(&self, c: char) -> bool {
+        c.is_ascii_alphabetic() || c == '_' || self.ident_start.contains(&c)
+    }
+
+    fn is_ident_continue(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_' || self.ident_continue.contains(&c)
+    }
+
+    fn classify_word(&self, word: &str) -> Option<&'static str> {
+        let matches = |list: &[&str]| {
+            list.iter().any(|entry| {
+                if self.case_insensitive {
+                    entry.eq_ignore_ascii_case(word)
+                } else {
+                    *entry == word
+                }
+            })
+        };
+        if matches(self.keywords) {
+            Some("keyword")
+        } else if matches(self.literals) {
+            Some("literal")
+        } else if matches(self.types) {
+            Some("type")
+        } else if matches(self.built_ins) {
+            Some("built_in")
+        } else if matches(self.directives) {
+            Some("meta")
+        } else {
+            None
+        }
+    }
+}
+
+/// Highlights `code` as the language named by a fence info string, returning
+/// `None` if the language isn't one we know.
+pub fn highlight(lang: &str, code: &str) -> Option<String> {
+    find_language(lang).map(|language| Highlighter::new(language, code).run())
+}
+
+struct Highlighter<'a> {
+    language: &'static Language,
+    code: &'a str,
+    pos: usize,
+    out: String,
+    /// Only whitespace has been seen since the last newline
+    line_start: bool,
+    /// Class for the next identifier, set after a keyword such as `fn`
+    pending_title: Option<&'static str>,
+}
+
+impl<'a> Highlighter<'a> {
+    fn new(language: &'static Language, code: &'a str) -> Self {
+        Self {
+            language,
+            code,
+            pos: 0,
+            out: String::with_capacity(code.len() * 2),
+            line_start: true,
+            pending_title: None,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.code[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn run(mut self) -> String {
+        while let Some(c) = self.peek() {
+            if !self.token() {
+                self.plain(c);
+            }
+        }
+        self.out
+    }
+
+    /// Emits one highlighted token if one starts here.
+    fn token(&mut self) -> bool {
+        let language = self.language;
+        let rest = self.rest();
+
+        if language.line_comments.iter().any(|p| rest.starts_with(p)) {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            self.span("comment", len);
+            return true;
+        }
+        if let Some((open, close)) = language.block_comment {
+            if let Some(after) = rest.strip_prefix(open) {
+                let len = after
+                    .find(close)
+                    .map_or(rest.len(), |end| open.len() + end + close.len());
+                self.span("comment", len);
+                return true;
+            }
+        }
+        if let Some(len) = self.meta_len() {
+            self.span("meta", len);
+            return true;
+        }
+        if let Some(len) = self.string_len() {
+            self.span("string", len);
+            return true;
+        }
+        if language.lifetimes && rest.starts_with('\'') {
+            let ident = rest[1..]
+                .find(|c: char| !language.is_ident_continue(c))
+                .unwrap_or(rest.len() - 1);
+            if ident > 0 {
+                self.span("symbol", 1 + ident);
+                return true;
+            }
+        }
+        if language.variables && rest.starts_with('$') {
+            if let Some(len) = self.variable_len() {
+                self.span("variable", len);
+                return true;
+            }
+        }
+        let c = rest.chars().next().unwrap_or_default();
+        if c.is_ascii_digit() {
+            let len = self.number_len();
+            self.span("number", len);
+            return true;
+        }
+        if language.is_ident_start(c) {
+            self.word();
+            return true;
+        }
+        false
+    }
+
+    fn meta_len(&self) -> Option<usize> {
+        let rest = self.rest();
+        match self.language.meta {
+            Meta::None => None,
+            Meta::Preprocessor => (self.line_start && rest.starts_with('#'))
+                .then(|| rest.find('\n').unwrap_or(rest.len())),
+            Meta::Attribute => {
+                if !(rest.starts_with("#[") || rest.starts_with("#![")) {
+                    return None;
+                }
+                let mut depth = 0;
+                for (i, c) in rest.char_indices() {
+                    match c {
+                        '[' => depth += 1,
+                        ']' if depth == 1 => return Some(i + 1),
+                        ']' => depth -= 1,
+                        '\n' => return Some(i),
+                        _ => {}
+                    }
+                }
+                Some(rest.len())
+            }
+            Meta::Prefixed(prefix) => {
+                let after = rest.strip_prefix(prefix)?;
+                let ident = after
+                    .find(|c: char| !self.language.is_ident_continue(c) && c != '.')
+                    .unwrap_or(after.len());
+                (ident > 0).then_some(prefix.len_utf8() + ident)
+            }
+        }
+    }
+
+    fn string_len(&self) -> Option<usize> {
+        let language = self.language;
+        let rest = self.rest();
+
+        if language.raw_strings {
+            if let Some(after) = rest.strip_prefix('r') {
+                let hashes = after.len() - after.trim_start_matches('#').len();
+                if after[hashes..].starts_with('"') {
+                    let close = format!("\"{}", "#".repeat(hashes));
+                    let body = 1 + hashes + 1;
+                    let len = rest[body..]
+                        .find(&close)
+                        .map_or(rest.len(), |end| body + end + close.len());
+                    return Some(len);
+                }
+            }
+        }
+
+        let quote = rest
+            .chars()
+            .next()
+            .filter(|c| language.quotes.contains(c))?;
+        if language.triple_quotes {
+            let triple: String = [quote; 3].iter().collect();
+            if rest.starts_with(&triple) {
+                let len = rest[3..]
+                    .find(&triple)
+                    .map_or(rest.len(), |end| 3 + end + 3);
+                return Some(len);
+            }
+        }
+        if language.lifetimes && quote == '\'' {
+            // a character literal is either `'\...'` or exactly one character
+            let mut chars = rest[1..].char_indices();
+            return match chars.next() {
+                Some((_, '\\')) => rest
+                    .get(3..)
+                    .and_then(|after| after.find('\''))
+                    .map(|end| 3 + end + 1),
+                Some((_, c)) => chars
+                    .next()
+                    .filter(|(_, close)| *close == '\'')
+                    .map(|(i, _)| 1 + i + 1)
+                    .filter(|_| c != '\''),
+                None => None,
+            };
+        }
+
+        let mut escaped = false;
+        for (i, c) in rest.char_indices().skip(1) {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '\n' if quote != '"' => return Some(i),
+                c if c == quote => return Some(i + 1),
+                _ => {}
+            }
+        }
+        Some(rest.len())
+    }
+
+    fn variable_len(&self) -> Option<usize> {
+        let rest = self.rest();
+        let after = &rest[1..];
+        let (open, close) = match after.chars().next()? {
+            '{' => ('{', '}'),
+            '(' => ('(', ')'),
+            c if c.is_ascii_alphanumeric() || "_@#?*!$-^<%+".contains(c) => {
+                let len = if c.is_ascii_alphabetic() || c == '_' {
+                    after
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(after.len())
+                } else {
+                    1
+                };
+                return Some(1 + len);
+            }
+            _ => return None,
+        };
+        let mut depth = 0;
+        for (i, c) in after.char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(1 + i + 1);
+                }
+            } else if c == '\n' {
+                return Some(1 + i);
+            }
+        }
+        Some(rest.len())
+    }
+
+    fn number_len(&self) -> usize {
+        let rest = self.rest();
+        let mut len = 0;
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let continues = c.is_ascii_alphanumeric()
+                || c == '_'
+                || (c == '.' && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit()))
+                || (self.language.sized_numbers && c == '\'');
+            if !continues {
+                break;
+            }
+            len = i + c.len_utf8();
+        }
+        len
+    }
+
+    fn word(&mut self) {
+        let language = self.language;
+        let rest = self.rest();
+        let mut len = rest
+            .find(|c: char| !language.is_ident_continue(c))
+            .unwrap_or(rest.len());
+        let word = &rest[..len];
+        let after = &rest[len..];
+
+        let class = if language.macros && after.starts_with('!') && !after.starts_with("!=") {
+            len += 1;
+            Some("built_in")
+        } else if let (Some(class), true) = (
+            language.labels,
+            self.line_start
+                && after.starts_with(':')
+                && !after.starts_with("::")
+                && !after.starts_with(":="),
+        ) {
+            Some(class)
+        } else if let Some(title) = self.pending_title.take() {
+            Some(title)
+        } else {
+            language.classify_word(word)
+        };
+
+        self.pending_title = language
+            .titles
+            .iter()
+            .find(|(keyword, _)| *keyword == word)
+            .map(|(_, title)| *title);
+
+        match class {
+            Some(class) => self.span(class, len),
+            None => {
+                escape_html(&mut self.out, word).unwrap();
+                self.pos += len;
+                self.line_start = false;
+            }
+        }
+    }
+
+    fn span(&mut self, class: &str, len: usize) {
+        let text = &self.code[self.pos..self.pos + len];
+        self.out.push_str("<span class=\"hljs-");
+        self.out.push_str(class);
+        self.out.push_str("\">");
+        escape_html(&mut self.out, text).unwrap();
+        self.out.push_str("</span>");
+        self.pos += len;
+        self.line_start = false;
+    }
+
+    fn plain(&mut self, c: char) {
+        let mut buf = [0; 4];
+        escape_html(&mut self.out, c.encode_utf8(&mut buf)).unwrap();
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line_start = true;
+        } else if !c.is_whitespace() {
+            self.line_start = false;
+            self.pending_title = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // one sample of each language, checked against the highlight.js class
+    // names that `public/hljs/styles` colours
+
+    #[test]
+    fn highlights_rust() {
+        let code = concat!(
+            "#[derive(Debug)]\n",
+            "fn main<'a>() -> u32 {\n",
+            "    // hi\n",
+            "    let s = r#\"x\"#;\n",
+            "    println!(\"{}\", 0x1f);\n",
+            "    true\n",
+            "}",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-meta\">#[derive(Debug)]</span>\n",
+            "<span class=\"hljs-keyword\">fn</span> <span class=\"hljs-title function_\">main</span>&lt;<span class=\"hljs-symbol\">'a</span>&gt;() -&gt; <span class=\"hljs-type\">u32</span> {\n",
+            "    <span class=\"hljs-comment\">// hi</span>\n",
+            "    <span class=\"hljs-keyword\">let</span> s = <span class=\"hljs-string\">r#&quot;x&quot;#</span>;\n",
+            "    <span class=\"hljs-built_in\">println!</span>(<span class=\"hljs-string\">&quot;{}&quot;</span>, <span class=\"hljs-number\">0x1f</span>);\n",
+            "    <span class=\"hljs-literal\">true</span>\n",
+            "}",
+        );
+        assert_eq!(highlight("rust", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_python() {
+        let code = concat!(
+            "@cache\n",
+            "def f(x: int) -> None:\n",
+            "    \"\"\"doc\"\"\"\n",
+            "    return print(x, True)  # c\n",
+            "class A: pass",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-meta\">@cache</span>\n",
+            "<span class=\"hljs-keyword\">def</span> <span class=\"hljs-title function_\">f</span>(x: <span class=\"hljs-built_in\">int</span>) -&gt; <span class=\"hljs-literal\">None</span>:\n",
+            "    <span class=\"hljs-string\">&quot;&quot;&quot;doc&quot;&quot;&quot;</span>\n",
+            "    <span class=\"hljs-keyword\">return</span> <span class=\"hljs-built_in\">print</span>(x, <span class=\"hljs-literal\">True</span>)  <span class=\"hljs-comment\"># c</span>\n",
+            "<span class=\"hljs-keyword\">class</span> <span class=\"hljs-title class_\">A</span>: <span class=\"hljs-keyword\">pass</span>",
+        );
+        assert_eq!(highlight("python", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_c() {
+        let code = concat!(
+            "#include <stdio.h>\n",
+            "struct s { int x; };\n",
+            "int main(void) {\n",
+            "    /* c */ return printf(\"%d\\n\", 42);\n",
+            "}",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-meta\">#include &lt;stdio.h&gt;</span>\n",
+            "<span class=\"hljs-keyword\">struct</span> <span class=\"hljs-title class_\">s</span> { <span class=\"hljs-type\">int</span> x; };\n",
+            "<span class=\"hljs-type\">int</span> main(<span class=\"hljs-type\">void</span>) {\n",
+            "    <span class=\"hljs-comment\">/* c */</span> <span class=\"hljs-keyword\">return</span> <span class=\"hljs-built_in\">printf</span>(<span class=\"hljs-string\">&quot;%d\\n&quot;</span>, <span class=\"hljs-number\">42</span>);\n",
+            "}",
+        );
+        assert_eq!(highlight("c", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_bash() {
+        let code = concat!(
+            "#!/bin/sh\n",
+            "for f in *.md; do\n",
+            "  echo \"$f ${HOME}\" # loop\n",
+            "done\n",
+            "export X=1",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-comment\">#!/bin/sh</span>\n",
+            "<span class=\"hljs-keyword\">for</span> f <span class=\"hljs-keyword\">in</span> *.md; <span class=\"hljs-keyword\">do</span>\n",
+            "  <span class=\"hljs-built_in\">echo</span> <span class=\"hljs-string\">&quot;$f ${HOME}&quot;</span> <span class=\"hljs-comment\"># loop</span>\n",
+            "<span class=\"hljs-keyword\">done</span>\n",
+            "<span class=\"hljs-built_in\">export</span> X=<span class=\"hljs-number\">1</span>",
+        );
+        assert_eq!(highlight("bash", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_makefile() {
+        let code = concat!("CC := gcc\n", "all: main.o\n", "\t$(CC) -o app $^ # link",);
+        let expected = concat!(
+            "CC := gcc\n",
+            "<span class=\"hljs-section\">all</span>: main.o\n",
+            "\t<span class=\"hljs-variable\">$(CC)</span> -o app <span class=\"hljs-variable\">$^</span> <span class=\"hljs-comment\"># link</span>",
+        );
+        assert_eq!(highlight("makefile", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_verilog() {
+        let code = concat!(
+            "module top(input wire clk);\n",
+            "  reg [3:0] q = 4'b1010;\n",
+            "  always @(posedge clk) q <= q + 1; // count\n",
+            "endmodule",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-keyword\">module</span> <span class=\"hljs-title\">top</span>(<span class=\"hljs-keyword\">input</span> <span class=\"hljs-type\">wire</span> clk);\n",
+            "  <span class=\"hljs-type\">reg</span> [<span class=\"hljs-number\">3</span>:<span class=\"hljs-number\">0</span>] q = <span class=\"hljs-number\">4'b1010</span>;\n",
+            "  <span class=\"hljs-keyword\">always</span> @(<span class=\"hljs-keyword\">posedge</span> clk) q &lt;= q + <span class=\"hljs-number\">1</span>; <span class=\"hljs-comment\">// count</span>\n",
+            "<span class=\"hljs-keyword\">endmodule</span>",
+        );
+        assert_eq!(highlight("verilog", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_x86asm() {
+        let code = concat!(
+            "section .text\n",
+            "start:\n",
+            "    mov eax, 1 ; exit\n",
+            "    int 0x80",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-meta\">section</span> <span class=\"hljs-meta\">.text</span>\n",
+            "<span class=\"hljs-symbol\">start</span>:\n",
+            "    <span class=\"hljs-keyword\">mov</span> <span class=\"hljs-built_in\">eax</span>, <span class=\"hljs-number\">1</span> <span class=\"hljs-comment\">; exit</span>\n",
+            "    <span class=\"hljs-keyword\">int</span> <span class=\"hljs-number\">0x80</span>",
+        );
+        assert_eq!(highlight("x86asm", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn highlights_riscv() {
+        let code = concat!(
+            ".globl _start\n",
+            "_start:\n",
+            "    li a0, 42 # answer\n",
+            "    ecall",
+        );
+        let expected = concat!(
+            "<span class=\"hljs-meta\">.globl</span> _start\n",
+            "<span class=\"hljs-symbol\">_start</span>:\n",
+            "    <span class=\"hljs-keyword\">li</span> <span class=\"hljs-built_in\">a0</span>, <span class=\"hljs-number\">42</span> <span class=\"hljs-comment\"># answer</span>\n",
+            "    <span class=\"hljs-keyword\">ecall</span>",
+        );
+        assert_eq!(highlight("riscv", code).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn leaves_unknown_languages_alone() {
+        assert_eq!(highlight("cobol", "DISPLAY 'HI'."), None);
+    }
+}
@@ -0,0 +1,760 @@
+//! Definitions of the languages our posts use. The word lists follow the
+//! highlight.js grammars of the same names.
+
+use super::{Language, Meta};
+
+const FUNCTION: &str = "title function_";
+const CLASS: &str = "title class_";
+
+const RUST: Language = Language {
+    names: &["rust", "rs"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+    raw_strings: true,
+    lifetimes: true,
+    macros: true,
+    meta: Meta::Attribute,
+    keywords: &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+        "do", "dyn", "else", "enum", "extern", "final", "fn", "for", "if", "impl", "in", "let",
+        "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+        "self", "Self", "static", "struct", "super", "trait", "try", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ],
+    literals: &["true", "false", "Some", "None", "Ok", "Err"],
+    built_ins: &[
+        "drop",
+        "Copy",
+        "Send",
+        "Sized",
+        "Sync",
+        "Drop",
+        "Fn",
+        "FnMut",
+        "FnOnce",
+        "ToOwned",
+        "Clone",
+        "Debug",
+        "PartialEq",
+        "PartialOrd",
+        "Eq",
+        "Ord",
+        "AsRef",
+        "AsMut",
+        "Into",
+        "From",
+        "Default",
+        "Iterator",
+        "Extend",
+        "IntoIterator",
+        "DoubleEndedIterator",
+        "ExactSizeIterator",
+        "ToString",
+    ],
+    types: &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+        "f32", "f64", "str", "char", "bool", "Box", "Option", "Result", "String", "Vec",
+    ],
+    titles: &[
+        ("fn", FUNCTION),
+        ("struct", CLASS),
+        ("enum", CLASS),
+        ("trait", CLASS),
+        ("union", CLASS),
+        ("type", CLASS),
+    ],
+    ..Language::DEFAULT
+};
+
+const PYTHON: Language = Language {
+    names: &["python", "py"],
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    triple_quotes: true,
+    meta: Meta::Prefixed('@'),
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "case", "class", "continue", "def",
+        "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+        "is", "lambda", "match", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+        "while", "with", "yield",
+    ],
+    literals: &[
+        "__debug__",
+        "Ellipsis",
+        "False",
+        "None",
+        "NotImplemented",
+        "True",
+    ],
+    built_ins: &[
+        "__import__",
+        "abs",
+        "all",
+        "any",
+        "ascii",
+        "bin",
+        "bool",
+        "breakpoint",
+        "bytearray",
+        "bytes",
+        "callable",
+        "chr",
+        "classmethod",
+        "compile",
+        "complex",
+        "delattr",
+        "dict",
+        "dir",
+        "divmod",
+        "enumerate",
+        "eval",
+        "exec",
+        "filter",
+        "float",
+        "format",
+        "frozenset",
+        "getattr",
+        "globals",
+        "hasattr",
+        "hash",
+        "help",
+        "hex",
+        "id",
+        "input",
+        "int",
+        "isinstance",
+        "issubclass",
+        "iter",
+        "len",
+        "list",
+        "locals",
+        "map",
+        "max",
+        "memoryview",
+        "min",
+        "next",
+        "object",
+        "oct",
+        "open",
+        "ord",
+        "pow",
+        "print",
+        "property",
+        "range",
+        "repr",
+        "reversed",
+        "round",
+        "set",
+        "setattr",
+        "slice",
+        "sorted",
+        "staticmethod",
+        "str",
+        "sum",
+        "super",
+        "tuple",
+        "type",
+        "vars",
+        "zip",
+    ],
+    types: &[
+        "Any",
+        "Callable",
+        "Coroutine",
+        "Dict",
+        "List",
+        "Literal",
+        "Generic",
+        "Optional",
+        "Sequence",
+        "Set",
+        "Tuple",
+        "Type",
+        "Union",
+    ],
+    titles: &[("def", FUNCTION), ("class", CLASS)],
+    ..Language::DEFAULT
+};
+
+const C: Language = Language {
+    names: &["c", "h"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+    meta: Meta::Preprocessor,
+    keywords: &[
+        "asm",
+        "auto",
+        "break",
+        "case",
+        "continue",
+        "default",
+        "do",
+        "else",
+        "enum",
+        "extern",
+        "for",
+        "fortran",
+        "goto",
+        "if",
+        "inline",
+        "register",
+        "restrict",
+        "return",
+        "sizeof",
+        "struct",
+        "switch",
+        "typedef",
+        "union",
+        "volatile",
+        "while",
+        "_Alignas",
+        "_Alignof",
+        "_Atomic",
+        "_Generic",
+        "_Noreturn",
+        "_Static_assert",
+        "_Thread_local",
+        "alignas",
+        "alignof",
+        "noreturn",
+        "static_assert",
+        "thread_local",
+        "_Pragma",
+    ],
+    types: &[
+        "float",
+        "double",
+        "signed",
+        "unsigned",
+        "int",
+        "short",
+        "long",
+        "char",
+        "void",
+        "_Bool",
+        "_Complex",
+        "_Imaginary",
+        "_Decimal32",
+        "_Decimal64",
+        "_Decimal128",
+        "const",
+        "static",
+        "complex",
+        "bool",
+        "imaginary",
+        "size_t",
+        "ssize_t",
+        "ptrdiff_t",
+        "uint8_t",
+        "uint16_t",
+        "uint32_t",
+        "uint64_t",
+        "int8_t",
+        "int16_t",
+        "int32_t",
+        "int64_t",
+        "uintptr_t",
+        "intptr_t",
+        "FILE",
+    ],
+    literals: &["true", "false", "NULL"],
+    built_ins: &[
+        "abort", "abs", "calloc", "exit", "fclose", "fgets", "fopen", "fprintf", "fputs", "free",
+        "fscanf", "fwrite", "malloc", "memcmp", "memcpy", "memset", "printf", "putchar", "puts",
+        "realloc", "scanf", "snprintf", "sprintf", "sscanf", "strcat", "strchr", "strcmp",
+        "strcpy", "strlen", "strncmp", "strncpy", "strstr",
+    ],
+    titles: &[("struct", CLASS), ("enum", CLASS), ("union", CLASS)],
+    ..Language::DEFAULT
+};
+
+const BASH: Language = Language {
+    names: &["bash", "sh", "zsh", "shell"],
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    variables: true,
+    ident_continue: &['-'],
+    keywords: &[
+        "if", "then", "else", "elif", "fi", "for", "while", "until", "in", "do", "done", "case",
+        "esac", "function", "select",
+    ],
+    literals: &["true", "false"],
+    built_ins: &[
+        "break",
+        "cd",
+        "continue",
+        "eval",
+        "exec",
+        "exit",
+        "export",
+        "getopts",
+        "hash",
+        "pwd",
+        "readonly",
+        "return",
+        "shift",
+        "test",
+        "times",
+        "trap",
+        "umask",
+        "unset",
+        "alias",
+        "bind",
+        "builtin",
+        "caller",
+        "command",
+        "declare",
+        "echo",
+        "enable",
+        "help",
+        "let",
+        "local",
+        "logout",
+        "mapfile",
+        "printf",
+        "read",
+        "readarray",
+        "source",
+        "type",
+        "typeset",
+        "ulimit",
+        "unalias",
+        "set",
+        "shopt",
+        "chmod",
+        "chown",
+        "cp",
+        "cat",
+        "cut",
+        "head",
+        "ln",
+        "ls",
+        "mkdir",
+        "mv",
+        "rm",
+        "rmdir",
+        "sort",
+        "tail",
+        "touch",
+        "tr",
+        "uniq",
+        "wc",
+        "which",
+        "sudo",
+        "kill",
+        "sleep",
+        "env",
+    ],
+    titles: &[("function", FUNCTION)],
+    ..Language::DEFAULT
+};
+
+const MAKEFILE: Language = Language {
+    names: &["makefile", "make", "mk", "mak"],
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    variables: true,
+    ident_start: &['.', '%', '/'],
+    ident_continue: &['.', '%', '/', '-'],
+    labels: Some("section"),
+    keywords: &[
+        "define", "endef", "undefine", "ifdef", "ifndef", "ifeq", "ifneq", "else", "endif",
+        "include", "-include", "sinclude", "override", "export", "unexport", "private", "vpath",
+    ],
+    ..Language::DEFAULT
+};
+
+const VERILOG: Language = Language {
+    names: &["verilog", "v", "sv", "svh", "systemverilog"],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    sized_numbers: true,
+    meta: Meta::Prefixed('`'),
+    ident_start: &['$'],
+    ident_continue: &['$'],
+    keywords: &[
+        "always",
+        "always_comb",
+        "always_ff",
+        "always_latch",
+        "and",
+        "assert",
+        "assign",
+        "automatic",
+        "begin",
+        "break",
+        "case",
+        "casex",
+        "casez",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "defparam",
+        "disable",
+        "do",
+        "edge",
+        "else",
+        "end",
+        "endcase",
+        "endclass",
+        "endfunction",
+        "endgenerate",
+        "endinterface",
+        "endmodule",
+        "endpackage",
+        "endtask",
+        "enum",
+        "extends",
+        "final",
+        "for",
+        "force",
+        "foreach",
+        "forever",
+        "fork",
+        "function",
+        "generate",
+        "genvar",
+        "if",
+        "import",
+        "initial",
+        "inout",
+        "input",
+        "interface",
+        "join",
+        "localparam",
+        "module",
+        "nand",
+        "negedge",
+        "nor",
+        "not",
+        "or",
+        "output",
+        "package",
+        "parameter",
+        "posedge",
+        "priority",
+        "release",
+        "repeat",
+        "return",
+        "signed",
+        "struct",
+        "task",
+        "typedef",
+        "union",
+        "unique",
+        "unsigned",
+        "wait",
+        "while",
+        "xnor",
+        "xor",
+    ],
+    types: &[
+        "bit", "byte", "int", "integer", "logic", "longint", "real", "reg", "shortint", "string",
+        "time", "tri", "wire", "wand", "wor", "supply0", "supply1",
+    ],
+    built_ins: &[
+        "$display",
+        "$write",
+        "$monitor",
+        "$strobe",
+        "$finish",
+        "$stop",
+        "$time",
+        "$realtime",
+        "$random",
+        "$urandom",
+        "$readmemh",
+        "$readmemb",
+        "$dumpfile",
+        "$dumpvars",
+        "$signed",
+        "$unsigned",
+        "$clog2",
+        "$bits",
+        "$error",
+        "$fatal",
+        "$warning",
+        "$info",
+    ],
+    titles: &[
+        ("module", "title"),
+        ("function", FUNCTION),
+        ("task", FUNCTION),
+    ],
+    ..Language::DEFAULT
+};
+
+const X86ASM: Language = Language {
+    names: &["x86asm", "x86", "nasm", "asm"],
+    case_insensitive: true,
+    line_comments: &[";"],
+    quotes: &['"', '\'', '`'],
+    ident_start: &['.'],
+    ident_continue: &['.'],
+    labels: Some("symbol"),
+    keywords: &[
+        "aaa", "adc", "add", "and", "bsf", "bsr", "bswap", "bt", "call", "cbw", "cdq", "cdqe",
+        "clc", "cld", "cli", "cmc", "cmova", "cmovae", "cmovb", "cmovbe", "cmove", "cmovg",
+        "cmovge", "cmovl", "cmovle", "cmovne", "cmp", "cmpxchg", "cpuid", "cqo", "cwd", "dec",
+        "div", "enter", "hlt", "idiv", "imul", "in", "inc", "int", "into", "iret", "ja", "jae",
+        "jb", "jbe", "jc", "jcxz", "je", "jecxz", "jg", "jge", "jl", "jle", "jmp", "jna", "jnae",
+        "jnb", "jnbe", "jnc", "jne", "jng", "jnge", "jnl", "jnle", "jno", "jnp", "jns", "jnz",
+        "jo", "jp", "jpe", "jpo", "js", "jz", "lea", "leave", "lock", "lodsb", "loop", "mov",
+        "movsb", "movsd", "movsx", "movsxd", "movzx", "mul", "neg", "nop", "not", "or", "out",
+        "pop", "popf", "push", "pushf", "rcl", "rcr", "rdtsc", "rep", "repe", "repne", "ret",
+        "rol", "ror", "sal", "sar", "sbb", "scasb", "seta", "setae", "setb", "setbe", "sete",
+        "setg", "setge", "setl", "setle", "setne", "shl", "shr", "stc", "std", "sti", "stosb",
+        "sub", "syscall", "sysenter", "sysret", "test", "xadd", "xchg", "xor",
+    ],
+    built_ins: &[
+        "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12",
+        "r13", "r14", "r15", "eax", "ebx", "ecx", "edx", "esi", "edi", "ebp", "esp", "r8d", "r9d",
+        "r10d", "r11d", "r12d", "r13d", "r14d", "r15d", "ax", "bx", "cx", "dx", "si", "di", "bp",
+        "sp", "al", "ah", "bl", "bh", "cl", "ch", "dl", "dh", "sil", "dil", "bpl", "spl", "cs",
+        "ds", "es", "fs", "gs", "ss", "rip", "eip", "byte", "word", "dword", "qword", "ptr",
+    ],
+    directives: &[
+        "section",
+        "segment",
+        "global",
+        "extern",
+        "bits",
+        "default",
+        "db",
+        "dw",
+        "dd",
+        "dq",
+        "resb",
+        "resw",
+        "resd",
+        "resq",
+        "times",
+        "equ",
+        "align",
+        ".text",
+        ".data",
+        ".bss",
+        ".rodata",
+        ".globl",
+        ".intel_syntax",
+    ],
+    ..Language::DEFAULT
+};
+
+const RISCV: Language = Language {
+    names: &["riscv", "riscvasm"],
+    case_insensitive: true,
+    line_comments: &["#"],
+    block_comment: Some(("/*", "*/")),
+    ident_start: &['.'],
+    ident_continue: &['.'],
+    labels: Some("symbol"),
+    keywords: &[
+        "lui",
+        "auipc",
+        "jal",
+        "jalr",
+        "beq",
+        "bne",
+        "blt",
+        "bge",
+        "bltu",
+        "bgeu",
+        "lb",
+        "lh",
+        "lw",
+        "lbu",
+        "lhu",
+        "sb",
+        "sh",
+        "sw",
+        "addi",
+        "slti",
+        "sltiu",
+        "xori",
+        "ori",
+        "andi",
+        "slli",
+        "srli",
+        "srai",
+        "add",
+        "sub",
+        "sll",
+        "slt",
+        "sltu",
+        "xor",
+        "srl",
+        "sra",
+        "or",
+        "and",
+        "fence",
+        "ecall",
+        "ebreak",
+        "lwu",
+        "ld",
+        "sd",
+        "addiw",
+        "slliw",
+        "srliw",
+        "sraiw",
+        "addw",
+        "subw",
+        "sllw",
+        "srlw",
+        "sraw",
+        "mul",
+        "mulh",
+        "mulhsu",
+        "mulhu",
+        "div",
+        "divu",
+        "rem",
+        "remu",
+        "mulw",
+        "divw",
+        "divuw",
+        "remw",
+        "remuw",
+        "lr.w",
+        "sc.w",
+        "lr.d",
+        "sc.d",
+        "flw",
+        "fsw",
+        "fld",
+        "fsd",
+        "fmadd.s",
+        "fmsub.s",
+        "fnmsub.s",
+        "fnmadd.s",
+        "fadd.s",
+        "fsub.s",
+        "fmul.s",
+        "fdiv.s",
+        "fsqrt.s",
+        "fsgnj.s",
+        "fsgnjn.s",
+        "fsgnjx.s",
+        "fmin.s",
+        "fmax.s",
+        "fcvt.w.s",
+        "fcvt.wu.s",
+        "fmv.x.w",
+        "feq.s",
+        "flt.s",
+        "fle.s",
+        "fclass.s",
+        "fcvt.s.w",
+        "fcvt.s.wu",
+        "fmv.w.x",
+        "fadd.d",
+        "fsub.d",
+        "fmul.d",
+        "fdiv.d",
+        "fsqrt.d",
+        "csrrw",
+        "csrrs",
+        "csrrc",
+        "csrrwi",
+        "csrrsi",
+        "csrrci",
+        "nop",
+        "li",
+        "la",
+        "mv",
+        "not",
+        "neg",
+        "negw",
+        "sext.w",
+        "seqz",
+        "snez",
+        "sltz",
+        "sgtz",
+        "beqz",
+        "bnez",
+        "blez",
+        "bgez",
+        "bltz",
+        "bgtz",
+        "bgt",
+        "ble",
+        "bgtu",
+        "bleu",
+        "j",
+        "jr",
+        "ret",
+        "call",
+        "tail",
+        "csrr",
+        "csrw",
+        "csrs",
+        "csrc",
+        "sret",
+        "mret",
+        "wfi",
+        "sfence.vma",
+    ],
+    built_ins: &[
+        "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13",
+        "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26",
+        "x27", "x28", "x29", "x30", "x31", "pc", "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7",
+        "f8", "f9", "f10", "f11", "f12", "f13", "f14", "f15", "f16", "f17", "f18", "f19", "f20",
+        "f21", "f22", "f23", "f24", "f25", "f26", "f27", "f28", "f29", "f30", "f31", "zero", "ra",
+        "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5", "a6",
+        "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4", "t5", "t6",
+        "fp",
+    ],
+    directives: &[
+        ".align",
+        ".file",
+        ".globl",
+        ".local",
+        ".comm",
+        ".common",
+        ".ident",
+        ".section",
+        ".size",
+        ".text",
+        ".data",
+        ".rodata",
+        ".bss",
+        ".string",
+        ".asciz",
+        ".equ",
+        ".macro",
+        ".endm",
+        ".type",
+        ".option",
+        ".2byte",
+        ".half",
+        ".short",
+        ".4byte",
+        ".word",
+        ".long",
+        ".8byte",
+        ".dword",
+        ".quad",
+        ".float",
+        ".double",
+        ".p2align",
+        ".balign",
+        ".zero",
+        ".attribute",
+    ],
+    ..Language::DEFAULT
+};
+
+const LANGUAGES: &[Language] = &[RUST, PYTHON, C, BASH, MAKEFILE, VERILOG, X86ASM, RISCV];
+
+/// Looks up a language by any of its fence names, ignoring case.
+pub(super) fn find_language(name: &str) -> Option<&'static Language> {
+    LANGUAGES.iter().find(|language| {
+        language
+            .names
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(name))
+    })
+}
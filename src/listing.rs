@@ -0,0 +1,241 @@
+//! Ordering and paging of post listings, shared by the blog index and the
+//! search page.
+
+use core::fmt;
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::post::Post;
+use crate::search;
+use crate::Route;
+
+/// Posts on a page when the URL doesn't ask for another number.
+pub const POSTS_PER_PAGE: usize = 10;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Sort {
+    #[default]
+    Newest,
+    Oldest,
+    Title,
+    /// Series by name, each in the order of its parts, then everything else
+    Series,
+    /// Best match for the text of a search first
+    Relevance,
+}
+
+impl Sort {
+    pub const ALL: [Self; 5] = [
+        Self::Newest,
+        Self::Oldest,
+        Self::Title,
+        Self::Series,
+        Self::Relevance,
+    ];
+
+    /// How the sort is written in URLs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Newest => "newest",
+            Self::Oldest => "oldest",
+            Self::Title => "title",
+            Self::Series => "series",
+            Self::Relevance => "relevance",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sort| sort.name() == name)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Newest => "Newest",
+            Self::Oldest => "Oldest",
+            Self::Title => "Title",
+            Self::Series => "Series",
+            Self::Relevance => "Relevance",
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Sorts `posts` by `sort`. Ties keep their order in `POST_LIST`, and
+/// [`Sort::Relevance`] without a search text falls back to [`Sort::Newest`].
+pub fn sort(posts: &mut [&Post], sort: Sort, q: Option<&str>) {
+    match (sort, q) {
+        (Sort::Relevance, Some(q)) => {
+            // scoring isn't cheap, so each post is scored once
+            let mut scored: Vec<(f32, &Post)> = posts
+                .iter()
+                .map(|post| (search::score(post, q).unwrap_or_default(), *post))
+                .collect();
+            scored.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+            for (slot, (_, post)) in posts.iter_mut().zip(scored) {
+                *slot = post;
+            }
+        }
+        (Sort::Newest | Sort::Relevance, _) => {
+            posts.sort_by_key(|post| std::cmp::Reverse(post.meta.published))
+        }
+        (Sort::Oldest, _) => posts.sort_by_key(|post| post.meta.published),
+        (Sort::Title, _) => posts.sort_by_key(|post| post.meta.title.to_lowercase()),
+        (Sort::Series, _) => posts.sort_by_key(|post| {
+            (
+                post.meta.series.is_none(),
+                post.meta.series,
+                post.meta.part,
+                std::cmp::Reverse(post.meta.published),
+            )
+        }),
+    }
+}
+
+/// One page of a listing.
+pub struct Page<'a, T> {
+    pub items: &'a [T],
+    /// Counted from 1
+    pub number: usize,
+    /// At least 1, even for an empty listing
+    pub count: usize,
+}
+
+/// Page `number` of `items`, counting from 1. Pages past the end are empty.
+pub fn page<T>(items: &[T], number: usize, per_page: usize) -> Page<'_, T> {
+    let per_page = per_page.max(1);
+    let number = number.max(1);
+    let start = (number - 1).saturating_mul(per_page).min(items.len());
+    let end = start.saturating_add(per_page).min(items.len());
+    Page {
+        items: &items[start..end],
+        number,
+        count: items.len().div_ceil(per_page).max(1),
+    }
+}
+
+/// The options of the paged blog index, `/blog/page/2?sort=oldest`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListOptions {
+    pub sort: Sort,
+    pub per_page: Option<usize>,
+}
+
+impl fmt::Display for ListOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sort={}", self.sort)?;
+        if let Some(per_page) = self.per_page {
+            write!(f, "&per_page={per_page}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Unknown keys and values that don't parse are ignored.
+impl FromQuery for ListOptions {
+    fn from_query(query: &str) -> Self {
+        let mut options = Self::default();
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "sort" => options.sort = Sort::parse(&value).unwrap_or_default(),
+                "per_page" => options.per_page = parse_page(&value),
+                _ => {}
+            }
+        }
+        options
+    }
+}
+
+/// Parses a page number or size, which has to be at least 1.
+pub fn parse_page(text: &str) -> Option<usize> {
+    text.parse().ok().filter(|&n| n > 0)
+}
+
+/// Links to the other sorts of a listing, with the current one in bold.
+#[component]
+pub fn SortLinks(cx: Scope, current: Sort, sorts: Vec<(Sort, Route)>) -> Element<'a> {
+    render! {
+        p {
+            class: "dark:text-white mb-4",
+            "Sort by: "
+            for (index, (sort, route)) in sorts.iter().enumerate() {
+                if index > 0 {
+                    rsx! { " • " }
+                }
+                if sort == current {
+                    rsx! {
+                        b {
+                            sort.label()
+                        }
+                    }
+                } else {
+                    rsx! {
+                        Link {
+                            class: "text-blue-400 dark:text-orange-600",
+                            to: route.clone(),
+                            sort.label()
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Previous and next links around the number of the current page, hidden
+/// when there's only one page.
+#[component]
+pub fn Pager(
+    cx: Scope,
+    number: usize,
+    count: usize,
+    #[props(!optional)] previous: Option<Route>,
+    #[props(!optional)] next: Option<Route>,
+) -> Element<'a> {
+    // components can't return `None` during server rendering
+    let paged = *count > 1 || *number > 1;
+    render! {
+        if paged {
+            rsx! {
+                nav {
+                    class: "flex justify-between dark:text-white",
+                    "aria-label": "Pages",
+                    span {
+                        if let Some(previous) = previous {
+                            rsx! {
+                                Link {
+                                    class: "text-blue-400 dark:text-orange-600",
+                                    to: previous.clone(),
+                                    u {
+                                        "<< Previous"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    span {
+                        "Page {number} of {count}"
+                    }
+                    span {
+                        if let Some(next) = next {
+                            rsx! {
+                                Link {
+                                    class: "text-blue-400 dark:text-orange-600",
+                                    to: next.clone(),
+                                    u {
+                                        "Next >>"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,20 +1,49 @@
 mod about;
+mod archive;
+mod authors;
+mod backlinks;
+mod category;
+#[cfg(feature = "ssr")]
+mod export;
+mod facets;
+#[cfg(feature = "ssr")]
+mod feed;
+mod highlight;
 mod home;
+mod listing;
 mod markdown;
 mod post;
+mod post_card;
+mod related;
 mod resume;
+mod search;
+mod search_box;
+mod series;
+#[cfg(feature = "ssr")]
+mod server;
+mod tags;
+mod toc;
 
 use crate::about::About;
+use crate::archive::{Archive, ArchiveMonth, ArchiveYear};
+use crate::authors::Author;
 use crate::home::Home;
-use crate::post::{Blog, Post, PostList, PostQuery};
+use crate::listing::ListOptions;
+use crate::post::{Blog, Post, PostList, PostListPage, PostQuery};
 use crate::resume::Resume;
+use crate::search_box::SearchBox;
+use crate::series::Series;
+use crate::tags::{Tag, Tags};
 
 use dioxus::prelude::*;
 use dioxus_fullstack::prelude::*;
 use dioxus_router::prelude::*;
 use post::PostQuerySegments;
 
-#[derive(Routable, Clone)]
+// a route is only built for a link or a navigation, so a big search query
+// isn't worth boxing (which `FromQuery` doesn't support anyway)
+#[allow(clippy::large_enum_variant)]
+#[derive(Routable, Clone, PartialEq)]
 #[rustfmt::skip]
 enum Route {
     #[layout(NavBar)]
@@ -28,15 +57,50 @@ enum Route {
             #[layout(Blog)]
             #[route("/")]
             PostList {},
+            #[route("/page/:page?:options")]
+            PostListPage { page: usize, options: ListOptions },
+            #[route("/tags")]
+            Tags {},
+            #[route("/tags/:tag")]
+            Tag { tag: String },
+            #[route("/archive")]
+            Archive {},
+            #[route("/archive/:year")]
+            ArchiveYear { year: i32 },
+            #[route("/archive/:year/:month")]
+            ArchiveMonth { year: i32, month: u8 },
             #[route("/:id")]
             Post { id: String },
+            #[route("/series/:slug")]
+            Series { slug: String },
+            #[route("/authors/:id")]
+            Author { id: String },
             #[end_layout]
         #[end_nest]
         #[route("/search/?:query_params")]
         PostQuery { query_params: PostQuerySegments },
 }
 
+/// Whether the web bundle is for the static file server `website export`
+/// writes the site for, which only has the feeds of the whole blog. Set
+/// `STATIC_EXPORT` at build time for it.
+pub const STATIC_EXPORT: bool = option_env!("STATIC_EXPORT").is_some();
+
+/// Paths of the feeds, which the `ssr` server serves next to the app. Both
+/// accept the same query string as [`Route::PostQuery`].
+pub const RSS_FEED: &str = "/feed.xml";
+pub const ATOM_FEED: &str = "/atom.xml";
+
 fn main() {
+    #[cfg(feature = "ssr")]
+    {
+        let mut args = std::env::args().skip(1);
+        match args.next().as_deref() {
+            Some("export") => export::main(args.next()),
+            _ => server::launch(),
+        }
+    }
+    #[cfg(not(feature = "ssr"))]
     LaunchBuilder::new(App).launch();
 }
 
@@ -47,7 +111,7 @@ fn App(cx: Scope) -> Element {
             class: "flex flex-col min-h-screen bg-gray-100 dark:bg-gray-900 dark:text-white",
             div {
                 class: "flex-grow container mx-auto px-4 dark:text-white",
-                Router::<Route> {}
+                Router::<Route> { config: router_config }
             }
             footer {
                 class: "bg-gray-100 dark:bg-gray-900 p-8",
@@ -56,11 +120,29 @@ fn App(cx: Scope) -> Element {
     })
 }
 
+/// On the server the router starts at the requested path, so each page is
+/// rendered (and hydrated) as itself rather than as the home page. Without a
+/// request to go by, it starts at the home page as usual.
+fn router_config() -> RouterConfig<Route> {
+    #[cfg(feature = "ssr")]
+    {
+        let route = server_context()
+            .request_parts()
+            .ok()
+            .and_then(|parts| parts.uri.to_string().parse().ok())
+            .unwrap_or(Route::Home {});
+        RouterConfig::default().history(MemoryHistory::with_initial_path(route))
+    }
+    #[cfg(not(feature = "ssr"))]
+    RouterConfig::default()
+}
+
 #[component]
 fn NavBar(cx: Scope) -> Element {
     cx.render(rsx! {
         div {
-            class: "flex justify-end py-4",
+            class: "flex justify-end items-center py-4",
+            SearchBox {}
             Link {
                 class: "text-gray-700 mx-2 dark:text-slate-200",
                 to: Route::Home {},
@@ -1,71 +1,85 @@
+use std::collections::HashSet;
+
+// only the build script lists the links of a whole post
+#[allow(dead_code)]
+mod links;
+
 use dioxus::prelude::*;
-use pulldown_cmark::{html, Options, Parser};
+use pulldown_cmark::escape::{escape_href, escape_html};
+use pulldown_cmark::CowStr;
+use pulldown_cmark::{html, Alignment, CodeBlockKind, Event, LinkType, Parser, Tag};
+
+use crate::highlight::highlight;
+use crate::Route;
+
+/// Classes attached to each kind of element the Markdown renderer emits.
+/// Elements without an entry here (lists, quotes, images, ...) are rendered
+/// unstyled, and raw HTML written in a post is always passed through as-is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarkdownTheme {
+    pub paragraph: &'static str,
+    /// `h1` through `h6`
+    pub headings: [&'static str; 6],
+    pub table: &'static str,
+    pub table_head_cell: &'static str,
+    pub table_cell: &'static str,
+    pub code_block: &'static str,
+    pub link: &'static str,
+    /// The `#` link to each heading, shown while hovering it, which is left
+    /// out if `None`
+    pub permalink: Option<&'static str>,
+}
+
+impl MarkdownTheme {
+    /// The look of blog posts.
+    pub const POST: Self = Self {
+        paragraph: "mb-4 dark:text-white",
+        headings: [
+            "text-4xl font-bold mb-2 dark:text-white",
+            "text-3xl font-bold mb-2 dark:text-white",
+            "text-2xl font-bold mb-2 dark:text-white",
+            "text-xl font-bold mb-2 dark:text-white",
+            "text-lg font-bold mb-2 dark:text-white",
+            "text-base font-bold mb-2 dark:text-white",
+        ],
+        table: "mb-4 gray-400 dark:text-white dark:gray-800",
+        table_head_cell: "bg-gray-300 dark:bg-gray-900",
+        table_cell: "bg-gray-100 dark:bg-gray-700",
+        code_block: "mb-4 bg-gray-200 dark:text-white dark:bg-gray-900",
+        link: "text-blue-400 dark:text-orange-600",
+        permalink: Some("ml-2 text-gray-400 opacity-0 group-hover:opacity-100 focus:opacity-100"),
+    };
+
+    /// No classes at all, for HTML that leaves the site (e.g. feeds).
+    #[cfg(feature = "ssr")]
+    pub const PLAIN: Self = Self {
+        paragraph: "",
+        headings: [""; 6],
+        table: "",
+        table_head_cell: "",
+        table_cell: "",
+        code_block: "",
+        link: "",
+        permalink: None,
+    };
+}
+
+impl Default for MarkdownTheme {
+    fn default() -> Self {
+        Self::POST
+    }
+}
 
 #[derive(Props, Clone, PartialEq, Debug, Copy)]
 pub struct MarkdownProps {
     pub content: &'static str,
+    #[props(default)]
+    pub theme: MarkdownTheme,
 }
 
 #[allow(non_snake_case)]
 pub fn Markdown(cx: Scope<MarkdownProps>) -> Element {
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    options.insert(Options::ENABLE_TABLES);
-    options.insert(Options::ENABLE_FOOTNOTES);
-    options.insert(Options::ENABLE_TASKLISTS);
-    let parser = Parser::new_ext(cx.props.content, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-
-    // add tailwind class to p and table
-    let html_output = html_output
-        .replace("<p>", "<p class=\"mb-4 dark:text-white\">")
-        .replace(
-            "<table>",
-            "<table class=\"mb-4 gray-400 dark:text-white dark:gray-800\">",
-        );
-
-    // add tailwind class to h<4-n>
-    let html_output = html_output
-        .replace(
-            "<h1>",
-            "<h1 class=\"text-4xl font-bold mb-2 dark:text-white\">",
-        )
-        .replace(
-            "<h2>",
-            "<h2 class=\"text-3xl font-bold mb-2 dark:text-white\">",
-        )
-        .replace(
-            "<h3>",
-            "<h3 class=\"text-2xl font-bold mb-2 dark:text-white\">",
-        )
-        .replace(
-            "<h4>",
-            "<h4 class=\"text-xl font-bold mb-2 dark:text-white\">",
-        )
-        .replace(
-            "<h5>",
-            "<h5 class=\"text-lg font-bold mb-2 dark:text-white\">",
-        )
-        .replace(
-            "<h6>",
-            "<h6 class=\"text-base font-bold mb-2 dark:text-white\">",
-        );
-
-    // add tailwind to code blocks
-    let html_output = html_output.replace(
-        "<pre>",
-        "<pre class=\"mb-4 bg-gray-200 dark:text-white dark:bg-gray-900\">",
-    );
-
-    // add tailwind to td and th
-    let html_output = html_output
-        .replace("<th>", "<th class=\"bg-gray-300 dark:bg-gray-900\">")
-        .replace("<td>", "<th class=\"bg-gray-100 dark:bg-gray-700\">");
-
-    // add tailwind to links
-    let html_output =
-        html_output.replace("<a ", "<a class=\"text-blue-400 dark:text-orange-600\" ");
+    let html_output = render_html(cx.props.content, &cx.props.theme);
 
     cx.render(rsx! {
         div {
@@ -73,3 +87,310 @@ pub fn Markdown(cx: Scope<MarkdownProps>) -> Element {
         }
     })
 }
+
+/// Renders `content` to an HTML string, styling each element with `theme`.
+/// Every heading gets the id [`headings`] gives it, and relative links to
+/// other posts go to their page.
+pub fn render_html(content: &str, theme: &MarkdownTheme) -> String {
+    let ids = headings(content).into_iter().map(|heading| heading.id);
+
+    let mut styler = Styler::new(theme, ids.collect());
+    let mut html_output = String::new();
+    html::push_html(
+        &mut html_output,
+        parser(content).map(|event| styler.style(event)),
+    );
+    html_output
+}
+
+fn parser(content: &str) -> Parser<'_, '_> {
+    Parser::new_ext(content, links::options())
+}
+
+/// A heading of a Markdown document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heading {
+    /// 1 for `#` through 6 for `######`
+    pub level: u8,
+    /// Slug of the text, with `-1`, `-2`, ... added to repeats so that each
+    /// heading of a document has its own
+    pub id: String,
+    pub text: String,
+}
+
+/// Every heading of `content`, in order.
+pub fn headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut current: Option<(u8, Option<String>, String)> = None;
+    let mut taken = HashSet::new();
+    for event in parser(content) {
+        match event {
+            Event::Start(Tag::Heading(level, id, _)) => {
+                current = Some((level as u8, id.map(str::to_string), String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, heading)) = &mut current {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(Tag::Heading(..)) => {
+                if let Some((level, id, text)) = current.take() {
+                    let id = unique(id.unwrap_or_else(|| slug(&text)), &mut taken);
+                    headings.push(Heading { level, id, text });
+                }
+            }
+            _ => {}
+        }
+    }
+    headings
+}
+
+/// `Hello, World!` becomes `hello-world`: the lowercase letters and digits of
+/// each word, joined by `-`.
+fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for word in text.split(|c: char| c.is_whitespace() || c == '-') {
+        let word = word.chars().filter(|c| c.is_alphanumeric());
+        let word: String = word.flat_map(char::to_lowercase).collect();
+        if word.is_empty() {
+            continue;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    if slug.is_empty() {
+        slug.push_str("section");
+    }
+    slug
+}
+
+/// `id`, or the first of `id-1`, `id-2`, ... that isn't `taken` yet.
+fn unique(id: String, taken: &mut HashSet<String>) -> String {
+    let id = if taken.contains(&id) {
+        (1..)
+            .map(|n| format!("{id}-{n}"))
+            .find(|candidate| !taken.contains(candidate))
+            .expect("there are endless candidates")
+    } else {
+        id
+    };
+    taken.insert(id.clone());
+    id
+}
+
+/// Replaces the start and end events of themed elements with the equivalent
+/// HTML carrying the theme's classes, and passes everything else through to
+/// pulldown-cmark's own writer. The text of fenced code blocks is collected
+/// and emitted syntax highlighted when the block ends.
+struct Styler<'t> {
+    theme: &'t MarkdownTheme,
+    /// Ids of the headings not reached yet, in order
+    heading_ids: std::vec::IntoIter<String>,
+    /// Id of the heading being written
+    heading_id: Option<String>,
+    table_alignments: Vec<Alignment>,
+    in_table_head: bool,
+    table_cell_index: usize,
+    /// Language and text of the fenced code block being collected
+    code_block: Option<(String, String)>,
+}
+
+impl<'t> Styler<'t> {
+    fn new(theme: &'t MarkdownTheme, heading_ids: Vec<String>) -> Self {
+        Self {
+            theme,
+            heading_ids: heading_ids.into_iter(),
+            heading_id: None,
+            table_alignments: Vec::new(),
+            in_table_head: false,
+            table_cell_index: 0,
+            code_block: None,
+        }
+    }
+
+    fn style<'a>(&mut self, event: Event<'a>) -> Event<'a> {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code_block {
+                Some((_, code)) => {
+                    code.push_str(&text);
+                    Event::Html(CowStr::Borrowed(""))
+                }
+                None => Event::Text(text),
+            },
+            event => event,
+        }
+    }
+
+    fn start<'a>(&mut self, tag: Tag<'a>) -> Event<'a> {
+        let html = match &tag {
+            Tag::Paragraph => open("p", self.theme.paragraph),
+            Tag::Heading(level, _, classes) => {
+                let mut html = format!("<{level}");
+                self.heading_id = self.heading_ids.next();
+                if let Some(id) = &self.heading_id {
+                    html.push_str(" id=\"");
+                    escape_html(&mut html, id).unwrap();
+                    html.push('"');
+                }
+                let mut class = self.theme.headings[*level as usize - 1].to_string();
+                if self.theme.permalink.is_some() {
+                    class.push_str(" group");
+                }
+                for extra in classes {
+                    class.push(' ');
+                    class.push_str(extra);
+                }
+                push_class(&mut html, &class);
+                html.push('>');
+                html
+            }
+            Tag::Table(alignments) => {
+                self.table_alignments = alignments.clone();
+                open("table", self.theme.table)
+            }
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.table_cell_index = 0;
+                "<thead><tr>".to_string()
+            }
+            Tag::TableRow => {
+                self.table_cell_index = 0;
+                "<tr>".to_string()
+            }
+            Tag::TableCell => {
+                let (name, class) = if self.in_table_head {
+                    ("th", self.theme.table_head_cell)
+                } else {
+                    ("td", self.theme.table_cell)
+                };
+                let mut html = format!("<{name}");
+                push_class(&mut html, class);
+                match self.table_alignments.get(self.table_cell_index) {
+                    Some(Alignment::Left) => html.push_str(" style=\"text-align: left\""),
+                    Some(Alignment::Center) => html.push_str(" style=\"text-align: center\""),
+                    Some(Alignment::Right) => html.push_str(" style=\"text-align: right\""),
+                    _ => {}
+                }
+                html.push('>');
+                html
+            }
+            Tag::CodeBlock(kind) => {
+                let mut html = open("pre", self.theme.code_block);
+                match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        let lang = info.split(' ').next().unwrap_or_default();
+                        if lang.is_empty() {
+                            html.push_str("<code class=\"hljs\">");
+                        } else {
+                            html.push_str("<code class=\"hljs language-");
+                            escape_html(&mut html, lang).unwrap();
+                            html.push_str("\">");
+                            self.code_block = Some((lang.to_string(), String::new()));
+                        }
+                    }
+                    CodeBlockKind::Indented => html.push_str("<code class=\"hljs\">"),
+                }
+                html
+            }
+            Tag::Link(link_type, dest, title) => {
+                let mut html = "<a".to_string();
+                push_class(&mut html, self.theme.link);
+                html.push_str(" href=\"");
+                let post = match link_type {
+                    LinkType::Email | LinkType::Autolink => None,
+                    _ => links::post_link(dest),
+                };
+                if *link_type == LinkType::Email {
+                    html.push_str("mailto:");
+                }
+                if let Some(post) = post {
+                    let route = Route::Post {
+                        id: post.id.to_string(),
+                    };
+                    escape_href(&mut html, &route.to_string()).unwrap();
+                    if let Some(fragment) = post.fragment {
+                        html.push('#');
+                        escape_href(&mut html, fragment).unwrap();
+                    }
+                } else {
+                    escape_href(&mut html, dest).unwrap();
+                }
+                html.push('"');
+                if !title.is_empty() {
+                    html.push_str(" title=\"");
+                    escape_html(&mut html, title).unwrap();
+                    html.push('"');
+                }
+                html.push('>');
+                html
+            }
+            _ => return Event::Start(tag),
+        };
+        Event::Html(html.into())
+    }
+
+    fn end<'a>(&mut self, tag: Tag<'a>) -> Event<'a> {
+        let html = match &tag {
+            Tag::Paragraph => "</p>\n".to_string(),
+            Tag::Heading(level, _, _) => {
+                let mut html = String::new();
+                if let (Some(class), Some(id)) = (self.theme.permalink, self.heading_id.take()) {
+                    html.push_str("<a");
+                    push_class(&mut html, class);
+                    html.push_str(" href=\"#");
+                    escape_href(&mut html, &id).unwrap();
+                    html.push_str("\" aria-label=\"Link to this section\">#</a>");
+                }
+                html.push_str(&format!("</{level}>\n"));
+                html
+            }
+            Tag::Table(_) => "</tbody></table>\n".to_string(),
+            Tag::TableHead => {
+                self.in_table_head = false;
+                "</tr></thead><tbody>\n".to_string()
+            }
+            Tag::TableRow => "</tr>\n".to_string(),
+            Tag::TableCell => {
+                self.table_cell_index += 1;
+                if self.in_table_head {
+                    "</th>".to_string()
+                } else {
+                    "</td>".to_string()
+                }
+            }
+            Tag::CodeBlock(_) => {
+                let mut html = String::new();
+                if let Some((lang, code)) = self.code_block.take() {
+                    match highlight(&lang, &code) {
+                        Some(highlighted) => html.push_str(&highlighted),
+                        None => escape_html(&mut html, &code).unwrap(),
+                    }
+                }
+                html.push_str("</code></pre>\n");
+                html
+            }
+            Tag::Link(..) => "</a>".to_string(),
+            _ => return Event::End(tag),
+        };
+        Event::Html(html.into())
+    }
+}
+
+fn open(name: &str, class: &str) -> String {
+    let mut html = format!("<{name}");
+    push_class(&mut html, class);
+    html.push('>');
+    html
+}
+
+fn push_class(html: &mut String, class: &str) {
+    if !class.is_empty() {
+        html.push_str(" class=\"");
+        escape_html(&mut *html, class).unwrap();
+        html.push('"');
+    }
+}
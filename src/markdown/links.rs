@@ -0,0 +1,94 @@
+//! Relative links from one post to another, such as `post-2.md` or
+//! `./post-2#setup`. The build script includes this file too, so the links it
+//! checks and lists are exactly the ones the renderer points at a post, found
+//! with the same [`options`].
+
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+
+/// The Markdown extensions posts are written with, for every parser of them
+/// to agree on.
+pub fn options() -> Options {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options
+}
+
+/// Where a relative link to another post goes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PostLink<'a> {
+    pub id: &'a str,
+    /// The heading it goes to, without the `#`
+    pub fragment: Option<&'a str>,
+}
+
+/// The post `dest` links to, if it's a relative link to one: a file stem with
+/// or without `.md`, optionally after `./` and before a `#fragment`. Absolute
+/// paths, URLs, links to other files and links within the page aren't.
+pub fn post_link(dest: &str) -> Option<PostLink<'_>> {
+    let (path, fragment) = match dest.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (dest, None),
+    };
+    let path = path.strip_prefix("./").unwrap_or(path);
+    let id = match path.strip_suffix(".md") {
+        Some(id) => id,
+        None if !path.contains('.') => path,
+        None => return None,
+    };
+    if id.is_empty() || id.contains(['/', '\\', ':', '?']) {
+        return None;
+    }
+    Some(PostLink { id, fragment })
+}
+
+/// Every relative link to a post in `markdown`, in order, as the destination
+/// written and the id of the post it goes to.
+pub fn post_links(markdown: &str) -> Vec<(String, String)> {
+    let mut links = Vec::new();
+    for event in Parser::new_ext(markdown, options()) {
+        let Event::Start(Tag::Link(link_type, dest, _)) = event else {
+            continue;
+        };
+        if matches!(link_type, LinkType::Email | LinkType::Autolink) {
+            continue;
+        }
+        if let Some(link) = post_link(&dest) {
+            links.push((dest.to_string(), link.id.to_string()));
+        }
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_relative_links_to_posts() {
+        let link = |id, fragment| Some(PostLink { id, fragment });
+        assert_eq!(post_link("post-1.md"), link("post-1", None));
+        assert_eq!(post_link("./post-1"), link("post-1", None));
+        assert_eq!(post_link("post-1.md#setup"), link("post-1", Some("setup")));
+        assert_eq!(post_link("https://example.org/post-1.md"), None);
+        assert_eq!(post_link("/blog/post-1"), None);
+        assert_eq!(post_link("image.png"), None);
+        assert_eq!(post_link("#setup"), None);
+    }
+
+    #[test]
+    fn finds_post_links_in_markdown() {
+        let markdown = "See [the first post](post-1.md) and \
+            [its setup](./post-1#setup), not <https://example.org> or \
+            [the logo](logo.svg).";
+        assert_eq!(
+            post_links(markdown),
+            [
+                ("post-1.md".to_string(), "post-1".to_string()),
+                ("./post-1#setup".to_string(), "post-1".to_string()),
+            ]
+        );
+    }
+}
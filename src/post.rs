@@ -2,152 +2,337 @@ use core::fmt;
 
 use dioxus::prelude::*;
 use dioxus_router::prelude::*;
-use time::macros::date;
+use time::macros::{date, datetime};
+use time::{Date, OffsetDateTime};
 
-use crate::{markdown::Markdown, Route};
+use crate::backlinks::ReferencedBy;
+use crate::category;
+use crate::facets::Facets;
+use crate::listing::{self, parse_page, ListOptions, Pager, Sort, SortLinks, POSTS_PER_PAGE};
+use crate::post_card::{PostCard, PostMeta};
+use crate::related::RelatedPosts;
+use crate::search::{self, Fragment};
+use crate::series::{self, SeriesContents, SeriesLinks};
+use crate::toc::TableOfContents;
+use crate::{markdown::Markdown, Route, ATOM_FEED, RSS_FEED, STATIC_EXPORT};
+
+/// Where a post is in its life. A post whose `publish_at` time hasn't come
+/// yet is scheduled, whatever its status, and can't be seen at all until then.
+///
+/// Scheduling only hides a post, it doesn't keep it secret. Every post is
+/// compiled into both the server and the web bundle, so a scheduled post's
+/// body is in the bundle and its text is in the search index for anyone who
+/// looks. And once the page has loaded, the bundle decides what's out by the
+/// reader's clock, so setting it ahead shows scheduled posts early. Don't
+/// deploy anything that mustn't be read before its `publish_at`.
+// only `POST_LIST` builds these, so which ones are used depends on assets/
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    /// Only in debug builds, where it's shown with a banner
+    Draft,
+    /// Can be opened at its URL, but isn't listed, searched or in the feeds
+    Unlisted,
+    Published,
+    /// Shown with a banner saying it may be out of date
+    Archived,
+}
 
 #[derive(Clone, Debug)]
-struct PostMetaData {
-    title: &'static str,
-    author: &'static str,
-    published: time::Date,
-    category: Option<&'static str>,
-    series: Option<&'static str>,
-    part: Option<u32>,
-    description: Option<&'static str>,
+pub struct PostMetaData {
+    pub title: &'static str,
+    /// Ids of entries in [`AUTHOR_LIST`](crate::authors::AUTHOR_LIST)
+    pub authors: &'static [&'static str],
+    pub published: time::Date,
+    /// When the post goes out, midnight UTC on `published` unless the front
+    /// matter gives a time
+    pub publish_at: OffsetDateTime,
+    pub status: Status,
+    pub category: Option<&'static str>,
+    pub series: Option<&'static str>,
+    pub part: Option<u32>,
+    pub description: Option<&'static str>,
+    pub tags: &'static [&'static str],
 }
 
 #[derive(Clone, Debug)]
-struct Post {
-    id: &'static str,
-    meta: PostMetaData,
-    content: &'static str,
+pub struct Post {
+    pub id: &'static str,
+    pub meta: PostMetaData,
+    pub content: &'static str,
+    /// Ids of the other posts `content` links to
+    pub links: &'static [&'static str],
+    /// Ids of the posts most like this one outside its series, best first
+    pub related: &'static [&'static str],
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// The search behind [`Route::PostQuery`], written to and read from the
+/// query string as `application/x-www-form-urlencoded`:
+///
+/// - `category`, `series`, `author` and `tag` list the values a post may have,
+///   either repeated (`category=Garbage&category=Trash`) or comma-separated
+///   (`category=Garbage,Trash`). A post matches a field if any of its values
+///   is listed, or any of its tags or authors, which are given by id;
+///   `join=true` asks for every listed field to match instead of any. A
+///   category also matches the posts in the categories below it, so
+///   `category=hardware` finds a post in `hardware/riscv`.
+/// - `-category`, `-series`, `-author` and `-tag` leave out posts with any of
+///   their values, e.g. `-author=author-1`, and `-category` leaves out the
+///   categories below its values too.
+/// - `after` and `before` keep posts published on or after and on or before a
+///   `YYYY-MM-DD` date.
+/// - `q` keeps posts containing every word of it, or another form of the
+///   word (`searching` finds `searches`), in their title, description or
+///   text. Results are then ranked by how well they match, see [`search`].
+/// - `sort`, `page` and `per_page` pick the order of the results and which of
+///   their pages to show, see [`listing`](crate::listing).
+///
+/// Exclusions, dates and `q` narrow the results whatever `join` says, and a
+/// query without any of the four value lists starts from every post.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct PostQuerySegments {
-    join: bool,
-    category: Option<String>,
-    series: Option<String>,
-    author: Option<String>,
+    pub join: bool,
+    pub category: Vec<String>,
+    pub series: Vec<String>,
+    pub author: Vec<String>,
+    pub tag: Vec<String>,
+    pub not_category: Vec<String>,
+    pub not_series: Vec<String>,
+    pub not_author: Vec<String>,
+    pub not_tag: Vec<String>,
+    pub after: Option<Date>,
+    pub before: Option<Date>,
+    pub q: Option<String>,
+    /// `None` sorts by relevance to `q`, or newest first without it
+    pub sort: Option<Sort>,
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+    /// Parameters this version of the site doesn't know, or couldn't parse,
+    /// in the order they came in, so links built from a query pass them on
+    pub extra: Vec<(String, String)>,
+}
+
+impl PostQuerySegments {
+    /// Whether `post` is one of this query's results.
+    pub fn matches(&self, post: &Post) -> bool {
+        let meta = &post.meta;
+        let categories: Vec<&str> = meta
+            .category
+            .into_iter()
+            .flat_map(category::ancestors)
+            .collect();
+        let fields = [
+            (&self.category, categories.as_slice()),
+            (&self.series, meta.series.as_slice()),
+            (&self.author, meta.authors),
+            (&self.tag, meta.tags),
+        ];
+        let mut included = fields
+            .iter()
+            .filter(|(values, _)| !values.is_empty())
+            .map(|(values, post_values)| contains(values, post_values))
+            .peekable();
+        let included = included.peek().is_none()
+            || if self.join {
+                included.all(|matched| matched)
+            } else {
+                included.any(|matched| matched)
+            };
+
+        let excluded = contains(&self.not_category, &categories)
+            || contains(&self.not_series, meta.series.as_slice())
+            || contains(&self.not_author, meta.authors)
+            || contains(&self.not_tag, meta.tags);
+
+        included
+            && !excluded
+            && self.after.is_none_or(|after| meta.published >= after)
+            && self.before.is_none_or(|before| meta.published <= before)
+            && self
+                .q
+                .as_deref()
+                .is_none_or(|q| search::score(post, q).is_some())
+    }
+
+    /// The value lists, by the key they are written under.
+    fn lists(&self) -> [(&'static str, &Vec<String>); 8] {
+        [
+            ("category", &self.category),
+            ("series", &self.series),
+            ("author", &self.author),
+            ("tag", &self.tag),
+            ("-category", &self.not_category),
+            ("-series", &self.not_series),
+            ("-author", &self.not_author),
+            ("-tag", &self.not_tag),
+        ]
+    }
+
+    fn list_mut(&mut self, key: &str) -> Option<&mut Vec<String>> {
+        match key {
+            "category" => Some(&mut self.category),
+            "series" => Some(&mut self.series),
+            "author" => Some(&mut self.author),
+            "tag" => Some(&mut self.tag),
+            "-category" => Some(&mut self.not_category),
+            "-series" => Some(&mut self.not_series),
+            "-author" => Some(&mut self.not_author),
+            "-tag" => Some(&mut self.not_tag),
+            _ => None,
+        }
+    }
 }
 
+/// Whether any of a post's values for a field is one of `values`.
+fn contains(values: &[String], post_values: &[&str]) -> bool {
+    post_values
+        .iter()
+        .any(|value| values.iter().any(|v| v == value))
+}
+
+/// The router percent-decodes the whole query string before handing it to
+/// [`FromQuery`], so the `%` escapes of the form encoding are escaped once
+/// more to survive that: `author=A%26B` is written as `author=A%2526B`.
+/// Spaces are written as `+`, which the router leaves alone. Commas inside a
+/// value are escaped too, so only the ones separating values stay bare.
+///
+/// `Route::PostQuery { query_params }.to_string().parse()` gives back the same
+/// `query_params` for any values, and so does
+/// [`PostQuerySegments::from_raw_query`] on the query string alone.
 impl fmt::Display for PostQuerySegments {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.join {
-            write!(f, "join=true")?;
-        } else {
-            write!(f, "join=false")?;
+        let mut pairs = vec![format!("join={}", self.join)];
+        for (key, values) in self.lists() {
+            if !values.is_empty() {
+                let values: Vec<String> = values.iter().map(|value| encode(value)).collect();
+                pairs.push(format!("{key}={}", values.join(",")));
+            }
         }
-        if let Some(category) = &self.category {
-            write!(f, "&")?;
-            write!(f, "category={}", category)?;
+        if let Some(after) = self.after {
+            pairs.push(format!("after={after}"));
         }
-        if let Some(series) = &self.series {
-            write!(f, "&")?;
-            write!(f, "series={}", series)?;
+        if let Some(before) = self.before {
+            pairs.push(format!("before={before}"));
         }
-        if let Some(author) = &self.author {
-            write!(f, "&")?;
-            write!(f, "author={}", author)?;
+        if let Some(q) = &self.q {
+            pairs.push(format!("q={}", encode(q)));
         }
-        Ok(())
+        if let Some(sort) = self.sort {
+            pairs.push(format!("sort={sort}"));
+        }
+        if let Some(page) = self.page {
+            pairs.push(format!("page={page}"));
+        }
+        if let Some(per_page) = self.per_page {
+            pairs.push(format!("per_page={per_page}"));
+        }
+        for (key, value) in &self.extra {
+            pairs.push(format!("{}={}", encode(key), encode(value)));
+        }
+        f.write_str(&pairs.join("&").replace('%', "%25"))
+    }
+}
+
+impl PostQuerySegments {
+    /// Path of `feed`, [`RSS_FEED`] or [`ATOM_FEED`], with every result of
+    /// this query, newest first.
+    pub fn feed_path(&self, feed: &str) -> String {
+        let query = Self {
+            sort: None,
+            page: None,
+            per_page: None,
+            ..self.clone()
+        };
+        format!("{feed}?{query}")
+    }
+
+    /// Parses a query string as it appears in a URL, e.g. one a server
+    /// handler received, by decoding it the way the router does first.
+    #[cfg(feature = "ssr")]
+    pub fn from_raw_query(query: &str) -> Self {
+        let decoded = dioxus_router::exports::urlencoding::decode(query);
+        Self::from_query(decoded.as_deref().unwrap_or(query))
     }
 }
 
 impl FromQuery for PostQuerySegments {
+    /// Parses a query string that the router has already percent-decoded
+    /// once, which is why [`Display`](fmt::Display) encodes it twice.
+    /// Repeated lists add up, and any other key given twice keeps its last
+    /// value.
     fn from_query(query: &str) -> Self {
-        let mut join = false;
-        let mut category = None;
-        let mut series = None;
-        let mut author = None;
-        let pairs = form_urlencoded::parse(query.as_bytes());
-        for (key, value) in pairs {
-            match key.as_ref() {
-                "join" => join = value == "true",
-                "category" => category = Some(value.to_string()),
-                "series" => series = Some(value.to_string()),
-                "author" => author = Some(value.to_string()),
-                _ => {}
+        let mut segments = Self::default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = decode(key);
+            if let Some(list) = segments.list_mut(&key) {
+                // split before decoding, so escaped commas stay in their value
+                list.extend(value.split(',').map(decode));
+                continue;
+            }
+            let value = decode(value);
+            match key.as_str() {
+                "join" => segments.join = value == "true",
+                "after" if parse_date(&value).is_some() => segments.after = parse_date(&value),
+                "before" if parse_date(&value).is_some() => segments.before = parse_date(&value),
+                "q" => segments.q = Some(value),
+                "sort" if Sort::parse(&value).is_some() => segments.sort = Sort::parse(&value),
+                "page" if parse_page(&value).is_some() => segments.page = parse_page(&value),
+                "per_page" if parse_page(&value).is_some() => {
+                    segments.per_page = parse_page(&value)
+                }
+                _ => segments.extra.push((key, value)),
             }
         }
-        Self {
-            join,
-            category,
-            series,
-            author,
-        }
+        segments
     }
 }
 
-// invariant: no two posts have the same id
-const POST_LIST: &[Post] = &[
-    Post {
-        id: "post-1",
-        meta: PostMetaData {
-            title: "Post 1",
-            author: "Author 1",
-            published: date!(2021 - 01 - 01),
-            category: Some("Garbage"),
-            series: None,
-            part: None,
-            description: None,
-        },
-        content: include_str!("../assets/post-1.md"),
-    },
-    Post {
-        id: "post-2",
-        meta: PostMetaData {
-            title: "Post 2",
-            author: "Author 2",
-            published: date!(2021 - 01 - 02),
-            category: None,
-            series: None,
-            part: None,
-            description: Some("This is the second test post"),
-        },
-        content: include_str!("../assets/post-2.md"),
-    },
-    Post {
-        id: "post-3",
-        meta: PostMetaData {
-            title: "Post 3",
-            author: "Author 2",
-            published: date!(2021 - 01 - 03),
-            category: Some("Garbage"),
-            series: Some("Test Series"),
-            part: Some(1),
-            description: None,
-        },
-        content: "blank",
-    },
-    Post {
-        id: "post-4",
-        meta: PostMetaData {
-            title: "Post 4",
-            author: "Author 1",
-            published: date!(2021 - 01 - 04),
-            category: Some("Trash"),
-            series: Some("Test Series"),
-            part: Some(2),
-            description: Some("Floating-point arithmetic instructions with one or two source operands use the R-type format with the OP-FP major opcode. FADD.S and FMUL.S perform single-precision floating-point addition and multiplication respectively, between rs1 and rs2. FSUB.S performs the single-precision floating-point subtraction of rs2 from rs1. FDIV.S performs the single-precision floating-point division of rs1 by rs2. FSQRT.S computes the square root of rs1. In each case, the result is written to rd."),
-        },
-        content: "blank",
-    },
-    Post {
-        id: "post-5",
-        meta: PostMetaData {
-            title: "Post 5",
-            author: "Author 1",
-            published: date!(2021 - 01 - 05),
-            category: Some("Trash"),
-            series: Some("Test Series"),
-            part: Some(3),
-            description: None,
-        },
-        content: include_str!("../assets/README.md"),
-    },
-];
+fn encode(component: &str) -> String {
+    form_urlencoded::byte_serialize(component.as_bytes()).collect()
+}
+
+fn decode(component: &str) -> String {
+    // `parse` decodes whole pairs, taking everything after the first `=` as
+    // the value
+    form_urlencoded::parse(format!("_={component}").as_bytes())
+        .next()
+        .map(|(_, value)| value.into_owned())
+        .unwrap_or_default()
+}
+
+/// Parses a `YYYY-MM-DD` date.
+fn parse_date(text: &str) -> Option<Date> {
+    let mut parts = text.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Date::from_calendar_date(year, month.try_into().ok()?, day).ok()
+}
+
+impl Post {
+    /// Whether the post is out: its `publish_at` time has passed. In the web
+    /// bundle this goes by the reader's clock; see [`Status`].
+    pub fn is_released(&self) -> bool {
+        self.meta.publish_at <= OffsetDateTime::now_utc()
+    }
+
+    /// Whether listings, search and feeds show the post.
+    pub fn is_listed(&self) -> bool {
+        self.is_released() && self.meta.status != Status::Unlisted
+    }
+}
+
+/// The posts listings, search and feeds show, in `POST_LIST` order.
+pub fn listed_posts() -> impl Iterator<Item = &'static Post> {
+    POST_LIST.iter().filter(|post| post.is_listed())
+}
+
+// generated by the build script from the front matter of each file in assets/
+// invariants (checked by build/validate.rs): no two posts have the same id,
+// every part belongs to a series, the parts of a series run 1..=n, and every
+// post in `links` exists
+pub const POST_LIST: &[Post] = include!(concat!(env!("OUT_DIR"), "/posts.rs"));
 
 #[component]
 pub fn Blog(cx: Scope) -> Element {
@@ -158,100 +343,119 @@ pub fn Blog(cx: Scope) -> Element {
 
 #[component]
 pub fn PostList(cx: Scope) -> Element {
-    cx.render(rsx! {
+    render! {
+        PostListPage {
+            page: 1,
+            options: ListOptions::default(),
+        }
+    }
+}
+
+/// Page `page` of the blog index, which is `/blog/` for the first page in the
+/// default order.
+fn list_route(page: usize, options: &ListOptions) -> Route {
+    if page == 1 && *options == ListOptions::default() {
+        Route::PostList {}
+    } else {
+        Route::PostListPage {
+            page,
+            options: options.clone(),
+        }
+    }
+}
+
+#[component]
+pub fn PostListPage(cx: Scope, page: usize, options: ListOptions) -> Element<'a> {
+    let mut posts: Vec<&Post> = listed_posts().collect();
+    listing::sort(&mut posts, options.sort, None);
+    let shown = listing::page(&posts, *page, options.per_page.unwrap_or(POSTS_PER_PAGE));
+    let sorts: Vec<(Sort, Route)> = Sort::ALL
+        .into_iter()
+        .filter(|sort| *sort != Sort::Relevance)
+        .map(|sort| {
+            let options = ListOptions {
+                sort,
+                ..options.clone()
+            };
+            (sort, list_route(1, &options))
+        })
+        .collect();
+    let previous =
+        (shown.number > 1).then(|| list_route((shown.number - 1).min(shown.count), options));
+    let next = (shown.number < shown.count).then(|| list_route(shown.number + 1, options));
+
+    render! {
         div {
             class: "bg-gray-200 dark:bg-gray-800 p-8",
             h1 {
                 class: "text-3xl dark:text-white font-bold mb-4",
                 "Blog"
             }
-            if POST_LIST.is_empty() {
+            p {
+                class: "dark:text-white mb-4",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::Tags {},
+                    "Browse posts by tag"
+                }
+                " • "
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::Archive {},
+                    "Browse the archive"
+                }
+            }
+            SortLinks {
+                current: options.sort,
+                sorts: sorts,
+            }
+            if posts.is_empty() {
                 rsx! {
                     p {
                         class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
                         "No posts here yet :p"
                     }
                 }
-            }
-            for post in POST_LIST {
-                p {
-                    class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
-                    p {
-                        class: "text-xl text-blue-400 dark:text-orange-600",
-                        Link {
-                            to: Route::Post { id: post.id.to_string() },
-                            u {
-                                post.meta.title
-                            }
-                        }
-                    }
+            } else if shown.items.is_empty() {
+                rsx! {
                     p {
-                        class: if post.meta.description.is_some() {
-                            "mb-4"
-                        } else {
-                            ""
-                        },
-                        "{post.meta.published} • "
-                        Link {
-                            class: "text-blue-400 dark:text-orange-600",
-                            to: Route::PostQuery {
-                                query_params: PostQuerySegments {
-                                    join: true,
-                                    category: None,
-                                    series: None,
-                                    author: Some(post.meta.author.to_string()),
-                                }
-                            },
-                            post.meta.author
-                        }
-                        if let Some(cat) = post.meta.category {
-                            rsx! {
-                                " • "
-                                Link {
-                                    class: "text-blue-400 dark:text-orange-600",
-                                    to: Route::PostQuery {
-                                        query_params: PostQuerySegments {
-                                            join: true,
-                                            category: Some(cat.to_string()),
-                                            series: None,
-                                            author: None,
-                                        }
-                                    },
-                                    cat
-                                }
-                            }
-                        }
-                    }
-                    if let Some(desc) = post.meta.description {
-                        rsx! {
-                            desc
-                            " "
-                            Link {
-                                class: "text-blue-400 dark:text-orange-600",
-                                to: Route::Post { id: post.id.to_string() },
-                                u {
-                                    ">>"
-                                },
-                            }
-                        }
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "There are only {shown.count} pages of posts."
                     }
                 }
             }
+            for post in shown.items {
+                PostCard {
+                    post: post,
+                    series_badge: true,
+                }
+            }
+            Pager {
+                number: shown.number,
+                count: shown.count,
+                previous: previous,
+                next: next,
+            }
         }
-    })
+    }
 }
 
 #[component]
-pub fn Post(cx: Scope, id: String) -> Element {
-    let post = POST_LIST.iter().find(|post| post.id == id);
+pub fn Post(cx: Scope, id: String) -> Element<'a> {
+    let post = POST_LIST
+        .iter()
+        .find(|post| post.id == id && post.is_released());
 
     if let Some(post) = post {
+        let banner = match post.meta.status {
+            Status::Draft => Some("This is a draft, which only development builds show."),
+            Status::Archived => Some("This post is archived, so it may be out of date."),
+            Status::Unlisted | Status::Published => None,
+        };
+
         cx.render(rsx! {
             div {
                 class: "bg-white pattern-light dark:text-white dark:bg-gray-800 p-8",
-                script {
-                    "hljs.highlightAll();"
-                }
                 p {
                     class: "dark:text-white mb-2",
                     Link {
@@ -262,6 +466,15 @@ pub fn Post(cx: Scope, id: String) -> Element {
                         }
                     }
                 }
+                if let Some(banner) = banner {
+                    rsx! {
+                        p {
+                            class: "mb-4 p-4 rounded bg-yellow-100 text-yellow-900 dark:bg-yellow-900 dark:text-yellow-100",
+                            role: "note",
+                            banner
+                        }
+                    }
+                }
                 h1 {
                     class: "text-4xl dark:text-white font-bold mb-2",
                     post.meta.title
@@ -280,14 +493,7 @@ pub fn Post(cx: Scope, id: String) -> Element {
                             " of "
                             Link {
                                 class: "text-blue-400 dark:text-orange-600",
-                                to: Route::PostQuery {
-                                    query_params: PostQuerySegments {
-                                        join: true,
-                                        category: None,
-                                        series: Some(series.to_string()),
-                                        author: None,
-                                    },
-                                },
+                                to: series::route(series),
                                 series
                             }
                         }
@@ -295,43 +501,40 @@ pub fn Post(cx: Scope, id: String) -> Element {
                 }
                 p {
                     class: "dark:text-white mb-4",
-                    "{post.meta.published} • "
-                    Link {
-                        class: "text-blue-400 dark:text-orange-600",
-                        to: Route::PostQuery {
-                            query_params: PostQuerySegments {
-                                join: true,
-                                category: None,
-                                series: None,
-                                author: Some(post.meta.author.to_string()),
-                            }
-                        },
-                        post.meta.author
+                    PostMeta {
+                        post: post,
                     }
-                    if let Some(cat) = post.meta.category {
-                        rsx! {
-                            " • "
-                            Link {
-                                class: "text-blue-400 dark:text-orange-600",
-                                to: Route::PostQuery {
-                                    query_params: PostQuerySegments {
-                                        join: true,
-                                        category: Some(cat.to_string()),
-                                        series: None,
-                                        author: None,
-                                    }
-                                },
-                                cat
-                            }
+                }
+                SeriesLinks {
+                    post: post,
+                }
+                SeriesContents {
+                    post: post,
+                }
+                div {
+                    class: "lg:flex lg:items-start gap-8",
+                    aside {
+                        class: "lg:order-last lg:w-64 shrink-0 lg:sticky lg:top-4",
+                        TableOfContents {
+                            content: post.content
                         }
                     }
-                }
-                p {
-                    class: "mb-4 dark:text-white",
-                    Markdown {
-                        content: post.content
+                    div {
+                        class: "mb-4 min-w-0 flex-1 dark:text-white",
+                        Markdown {
+                            content: post.content
+                        }
                     }
                 }
+                SeriesLinks {
+                    post: post,
+                }
+                ReferencedBy {
+                    post: post,
+                }
+                RelatedPosts {
+                    post: post,
+                }
             }
         })
     } else {
@@ -363,53 +566,51 @@ pub fn Post(cx: Scope, id: String) -> Element {
 }
 
 #[component]
-pub fn PostQuery(cx: Scope, query_params: PostQuerySegments) -> Element {
-    let mut posts = POST_LIST.to_vec();
-    if query_params.join {
-        posts = posts
-            .into_iter()
-            .filter(|post| {
-                if let Some(category) = &query_params.category {
-                    if post.meta.category != Some(category.as_str()) {
-                        return false;
-                    }
-                }
-                if let Some(series) = &query_params.series {
-                    if post.meta.series != Some(series.as_str()) {
-                        return false;
-                    }
-                }
-                if let Some(author) = &query_params.author {
-                    if post.meta.author != author.as_str() {
-                        return false;
-                    }
-                }
-                true
-            })
-            .collect();
+pub fn PostQuery(cx: Scope, query_params: PostQuerySegments) -> Element<'a> {
+    let q = query_params.q.as_deref();
+    let sort = query_params.sort.unwrap_or(if q.is_some() {
+        Sort::Relevance
     } else {
-        posts = posts
-            .into_iter()
-            .filter(|post| {
-                if let Some(category) = &query_params.category {
-                    if post.meta.category == Some(category.as_str()) {
-                        return true;
-                    }
-                }
-                if let Some(series) = &query_params.series {
-                    if post.meta.series == Some(series.as_str()) {
-                        return true;
-                    }
-                }
-                if let Some(author) = &query_params.author {
-                    if post.meta.author == author.as_str() {
-                        return true;
-                    }
-                }
-                false
-            })
-            .collect();
-    }
+        Sort::Newest
+    });
+    let mut posts: Vec<&Post> = listed_posts()
+        .filter(|post| query_params.matches(post))
+        .collect();
+    listing::sort(&mut posts, sort, q);
+    let shown = listing::page(
+        &posts,
+        query_params.page.unwrap_or(1),
+        query_params.per_page.unwrap_or(POSTS_PER_PAGE),
+    );
+    let results: Vec<(&Post, Option<Vec<Fragment>>)> = shown
+        .items
+        .iter()
+        .map(|post| (*post, q.and_then(|q| search::snippet(post, q))))
+        .collect();
+
+    let sorts: Vec<(Sort, Route)> = Sort::ALL
+        .into_iter()
+        .filter(|sort| q.is_some() || *sort != Sort::Relevance)
+        .map(|sort| {
+            let query_params = PostQuerySegments {
+                sort: Some(sort),
+                page: None,
+                ..query_params.clone()
+            };
+            (sort, Route::PostQuery { query_params })
+        })
+        .collect();
+    let page_route = |page: usize| Route::PostQuery {
+        query_params: PostQuerySegments {
+            page: (page > 1).then_some(page),
+            ..query_params.clone()
+        },
+    };
+    let previous = (shown.number > 1).then(|| page_route((shown.number - 1).min(shown.count)));
+    let next = (shown.number < shown.count).then(|| page_route(shown.number + 1));
+
+    let rss_feed = query_params.feed_path(RSS_FEED);
+    let atom_feed = query_params.feed_path(ATOM_FEED);
 
     render! {
         div {
@@ -425,78 +626,107 @@ pub fn PostQuery(cx: Scope, query_params: PostQuerySegments) -> Element {
                 class: "text-3xl dark:text-white font-bold mb-4",
                 "Search"
             }
-            if posts.is_empty() {
+            // a static server answers every feed query with the whole blog
+            if !STATIC_EXPORT {
                 rsx! {
                     p {
-                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
-                        "No posts found. Try broadening your search."
-                    }
-                }
-            }
-            for post in posts {
-                p {
-                    class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
-                    p {
-                        class: "text-xl text-blue-400 dark:text-orange-600",
-                        Link {
-                            to: Route::Post { id: post.id.to_string() },
+                        class: "dark:text-white mb-4",
+                        "Follow these results: "
+                        a {
+                            class: "text-blue-400 dark:text-orange-600",
+                            href: "{rss_feed}",
                             u {
-                                post.meta.title
+                                "RSS"
                             }
                         }
-                    }
-                    p {
-                        class: if post.meta.description.is_some() {
-                            "mb-4"
-                        } else {
-                            ""
-                        },
-                        "{post.meta.published} • "
-                        Link {
+                        " • "
+                        a {
                             class: "text-blue-400 dark:text-orange-600",
-                            to: Route::PostQuery {
-                                query_params: PostQuerySegments {
-                                    join: query_params.join,
-                                    category: query_params.category.clone(),
-                                    series: query_params.series.clone(),
-                                    author: Some(post.meta.author.to_string()),
-                                }
-                            },
-                            post.meta.author
-                        }
-                        if let Some(cat) = post.meta.category {
-                            rsx! {
-                                " • "
-                                Link {
-                                    class: "text-blue-400 dark:text-orange-600",
-                                    to: Route::PostQuery {
-                                        query_params: PostQuerySegments {
-                                            join: query_params.join,
-                                            category: Some(cat.to_string()),
-                                            series: query_params.series.clone(),
-                                            author: query_params.author.clone(),
-                                        }
-                                    },
-                                    cat
-                                }
+                            href: "{atom_feed}",
+                            u {
+                                "Atom"
                             }
                         }
                     }
-                    if let Some(desc) = post.meta.description {
+                }
+            }
+            div {
+                class: "flex flex-col md:flex-row gap-8",
+                Facets {
+                    query_params: query_params.clone(),
+                }
+                div {
+                    class: "flex-grow",
+                    SortLinks {
+                        current: sort,
+                        sorts: sorts,
+                    }
+                    if posts.is_empty() {
                         rsx! {
-                            desc
-                            " "
-                            Link {
-                                class: "text-blue-400 dark:text-orange-600",
-                                to: Route::Post { id: post.id.to_string() },
-                                u {
-                                    ">>"
-                                },
+                            p {
+                                class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                                "No posts found. Try broadening your search."
                             }
                         }
+                    } else if shown.items.is_empty() {
+                        rsx! {
+                            p {
+                                class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                                "There are only {shown.count} pages of results."
+                            }
+                        }
+                    }
+                    for (post, snippet) in results {
+                        PostCard {
+                            post: post,
+                            series_badge: true,
+                            query: query_params.clone(),
+                            snippet: snippet,
+                        }
+                    }
+                    Pager {
+                        number: shown.number,
+                        count: shown.count,
+                        previous: previous,
+                        next: next,
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_round_trips_through_route() {
+        let awkward = [
+            "a & b",
+            "x=y",
+            "100%",
+            "C++",
+            "two  spaces",
+            "comma, inside",
+            "Café ☕",
+            "%20",
+        ];
+        for value in awkward {
+            let query_params = PostQuerySegments {
+                join: true,
+                category: vec![value.to_string()],
+                series: vec![value.to_string(), "plain".to_string()],
+                not_tag: vec![value.to_string()],
+                q: Some(value.to_string()),
+                extra: vec![(value.to_string(), value.to_string())],
+                ..Default::default()
+            };
+            let route = Route::PostQuery { query_params };
+            let parsed: Route = route.to_string().parse().unwrap_or_else(|_| {
+                panic!("{value:?}: {route} doesn't parse");
+            });
+            assert!(parsed == route, "{value:?}: {route} parses differently");
+        }
+    }
+}
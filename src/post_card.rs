@@ -0,0 +1,175 @@
+//! The card a post is listed with, so every page that lists posts shows them
+//! the same way.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::authors::AuthorLinks;
+use crate::category::Breadcrumbs;
+use crate::post::{Post, PostQuerySegments};
+use crate::search::Fragment;
+use crate::series;
+use crate::Route;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CardLayout {
+    /// The title and the date, authors and category line
+    Compact,
+    /// Also the description, and the snippet if there is one
+    #[default]
+    Full,
+}
+
+/// The query a post's category links start from when the page doesn't have
+/// one of its own.
+fn every_post() -> PostQuerySegments {
+    PostQuerySegments {
+        join: true,
+        ..Default::default()
+    }
+}
+
+#[component]
+pub fn PostCard<'a>(
+    cx: Scope<'a>,
+    post: &'a Post,
+    #[props(default)] layout: CardLayout,
+    /// Shows which series the post is part of next to its title
+    #[props(default)]
+    series_badge: bool,
+    /// The query the metadata links narrow down, e.g. the search being shown
+    #[props(default = every_post())]
+    query: PostQuerySegments,
+    /// Matches of a text search in the post, shown by [`CardLayout::Full`]
+    #[props(!optional, default)]
+    snippet: Option<Vec<Fragment>>,
+) -> Element<'a> {
+    let full = *layout == CardLayout::Full;
+    let description = post.meta.description.filter(|_| full);
+    let snippet = snippet.as_ref().filter(|_| full);
+
+    render! {
+        article {
+            class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+            p {
+                class: "text-xl text-blue-400 dark:text-orange-600",
+                Link {
+                    to: Route::Post { id: post.id.to_string() },
+                    u {
+                        post.meta.title
+                    }
+                }
+                if *series_badge {
+                    rsx! {
+                        SeriesBadge {
+                            post: post,
+                        }
+                    }
+                }
+            }
+            p {
+                class: if description.is_some() {
+                    "mb-4"
+                } else {
+                    ""
+                },
+                PostMeta {
+                    post: post,
+                    query: query.clone(),
+                }
+            }
+            if let Some(desc) = description {
+                rsx! {
+                    desc
+                    " "
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::Post { id: post.id.to_string() },
+                        u {
+                            ">>"
+                        },
+                    }
+                }
+            }
+            if let Some(fragments) = snippet {
+                rsx! {
+                    p {
+                        class: "mt-2 text-sm",
+                        for fragment in fragments {
+                            if fragment.matched {
+                                rsx! {
+                                    mark {
+                                        class: "bg-yellow-200 dark:bg-orange-800 dark:text-white",
+                                        fragment.text
+                                    }
+                                }
+                            } else {
+                                rsx! {
+                                    fragment.text
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `date • authors • category › subcategory • #tags`, with each level of the
+/// category linking to `query` narrowed down to it, and each author and tag
+/// linking to their page.
+#[component]
+pub fn PostMeta<'a>(
+    cx: Scope<'a>,
+    post: &'a Post,
+    #[props(default = every_post())] query: PostQuerySegments,
+) -> Element<'a> {
+    render! {
+        "{post.meta.published} • "
+        AuthorLinks {
+            ids: post.meta.authors,
+        }
+        if let Some(category) = post.meta.category {
+            rsx! {
+                " • "
+                Breadcrumbs {
+                    path: category,
+                    query: query.clone(),
+                }
+            }
+        }
+        if !post.meta.tags.is_empty() {
+            rsx! { " •" }
+        }
+        for tag in post.meta.tags {
+            " "
+            Link {
+                class: "text-blue-400 dark:text-orange-600",
+                to: Route::Tag { tag: tag.to_string() },
+                "#{tag}"
+            }
+        }
+    }
+}
+
+/// A small label with the post's series and part, if it has one.
+#[component]
+fn SeriesBadge<'a>(cx: Scope<'a>, post: &'a Post) -> Element<'a> {
+    render! {
+        if let Some(series) = post.meta.series {
+            rsx! {
+                Link {
+                    class: "ml-2 align-middle text-sm rounded px-2 py-0.5 bg-blue-100 text-blue-800 dark:bg-orange-900 dark:text-orange-200",
+                    to: series::route(series),
+                    series
+                    if let Some(part) = post.meta.part {
+                        rsx! {
+                            " • Part {part}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
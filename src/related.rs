@@ -0,0 +1,61 @@
+//! Related posts, shown at the end of a post so there's somewhere to go next.
+
+use dioxus::prelude::*;
+
+use crate::listing::{self, Sort};
+use crate::post::{listed_posts, Post};
+use crate::post_card::{CardLayout, PostCard};
+
+/// Related posts shown under a post: at least three, when there are that
+/// many other posts outside its series, and at most five.
+const MIN_RELATED: usize = 3;
+const MAX_RELATED: usize = 5;
+
+/// The listed posts most related to `post`, best first. The build script
+/// ranks them and already leaves out the other parts of its series. When
+/// too few share anything with it, the newest other posts make up the rest.
+fn related(post: &Post) -> Vec<&'static Post> {
+    let mut related: Vec<&Post> = post
+        .related
+        .iter()
+        .filter_map(|id| listed_posts().find(|other| other.id == *id))
+        .take(MAX_RELATED)
+        .collect();
+    if related.len() < MIN_RELATED {
+        let mut recent: Vec<&Post> = listed_posts()
+            .filter(|other| other.id != post.id)
+            .filter(|other| post.meta.series.is_none() || other.meta.series != post.meta.series)
+            .filter(|other| related.iter().all(|related| related.id != other.id))
+            .collect();
+        listing::sort(&mut recent, Sort::Newest, None);
+        recent.truncate(MIN_RELATED - related.len());
+        related.extend(recent);
+    }
+    related
+}
+
+#[component]
+pub fn RelatedPosts<'a>(cx: Scope<'a>, post: &'a Post) -> Element<'a> {
+    let posts = related(post);
+
+    render! {
+        if !posts.is_empty() {
+            rsx! {
+                section {
+                    class: "mt-4",
+                    "aria-label": "Related posts",
+                    h2 {
+                        class: "text-2xl dark:text-white font-bold mb-4",
+                        "Related posts"
+                    }
+                    for post in posts {
+                        PostCard {
+                            post: post,
+                            layout: CardLayout::Compact,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Full-text search over the title, description and text of every post,
+//! answered from an index the build script generates, so it works the same
+//! in the browser and on the server without any requests.
+
+mod analyze;
+
+use analyze::tokens;
+
+use crate::post::{Post, POST_LIST};
+
+/// BM25 parameters: how quickly repeating a term stops adding to the score,
+/// and how much longer posts are penalised.
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+/// Words in a snippet.
+const SNIPPET_WORDS: usize = 30;
+
+struct SearchIndex {
+    /// Plain text of each post's body, in `POST_LIST` order
+    text: &'static [&'static str],
+    /// Weighted number of terms in each post
+    lengths: &'static [u32],
+    average_length: f32,
+    /// Every term with the posts it occurs in and its weighted frequency
+    /// there, sorted by term
+    terms: &'static [(&'static str, &'static [(usize, u32)])],
+}
+
+// generated by the build script from the same posts, in the same order, as
+// `POST_LIST`; title words count three times and description words twice
+const INDEX: SearchIndex = include!(concat!(env!("OUT_DIR"), "/search_index.rs"));
+
+/// A piece of a snippet, which is highlighted if it matched the query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fragment {
+    pub text: &'static str,
+    pub matched: bool,
+}
+
+/// The relevance of `post` to `query` by BM25, or `None` if the post doesn't
+/// contain every term of it. A query without any words matches everything.
+pub fn score(post: &Post, query: &str) -> Option<f32> {
+    INDEX.score(number(post)?, query)
+}
+
+/// The stretch of `post`'s text with the most words matching `query`, cut
+/// into highlighted and plain fragments, or `None` if its text has no match.
+pub fn snippet(post: &Post, query: &str) -> Option<Vec<Fragment>> {
+    INDEX.snippet(number(post)?, query)
+}
+
+impl SearchIndex {
+    /// [`score`] of the post numbered `number`.
+    fn score(&self, number: usize, query: &str) -> Option<f32> {
+        let length = self.lengths[number] as f32;
+        let mut score = 0.0;
+        for term in terms(query) {
+            let postings = self.postings(&term)?;
+            let (_, frequency) = postings.iter().find(|(post, _)| *post == number)?;
+            let frequency = *frequency as f32;
+            let containing = postings.len() as f32;
+            let idf =
+                (1.0 + (self.lengths.len() as f32 - containing + 0.5) / (containing + 0.5)).ln();
+            score += idf * frequency * (K1 + 1.0)
+                / (frequency + K1 * (1.0 - B + B * length / self.average_length));
+        }
+        Some(score)
+    }
+
+    /// [`snippet`] of the post numbered `number`.
+    fn snippet(&self, number: usize, query: &str) -> Option<Vec<Fragment>> {
+        let text = self.text[number];
+        let query = terms(query);
+        let words: Vec<_> = tokens(text)
+            .map(|token| {
+                let matched = query.contains(&token.term);
+                (token, matched)
+            })
+            .collect();
+
+        let matches_from = |start: usize| {
+            words[start..(start + SNIPPET_WORDS).min(words.len())]
+                .iter()
+                .filter(|(_, matched)| *matched)
+                .count()
+        };
+        let start = (0..words.len())
+            .max_by_key(|&start| (matches_from(start), std::cmp::Reverse(start)))?;
+        if matches_from(start) == 0 {
+            return None;
+        }
+        let end = (start + SNIPPET_WORDS).min(words.len());
+
+        let mut fragments = Vec::new();
+        let mut plain_from = if start == 0 {
+            0
+        } else {
+            fragments.push(Fragment {
+                text: "…",
+                matched: false,
+            });
+            words[start].0.start
+        };
+        for (word, _) in words[start..end].iter().filter(|(_, matched)| *matched) {
+            fragments.push(Fragment {
+                text: &text[plain_from..word.start],
+                matched: false,
+            });
+            fragments.push(Fragment {
+                text: &text[word.start..word.end],
+                matched: true,
+            });
+            plain_from = word.end;
+        }
+        if end == words.len() {
+            fragments.push(Fragment {
+                text: &text[plain_from..],
+                matched: false,
+            });
+        } else {
+            fragments.push(Fragment {
+                text: &text[plain_from..words[end - 1].0.end],
+                matched: false,
+            });
+            fragments.push(Fragment {
+                text: "…",
+                matched: false,
+            });
+        }
+        fragments.retain(|fragment| !fragment.text.is_empty());
+        Some(fragments)
+    }
+
+    fn postings(&self, term: &str) -> Option<&'static [(usize, u32)]> {
+        let found = self.terms.binary_search_by(|(t, _)| (*t).cmp(term)).ok()?;
+        Some(self.terms[found].1)
+    }
+}
+
+/// The distinct terms of a query.
+fn terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = tokens(query).map(|token| token.term).collect();
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+/// The position of `post` in `POST_LIST`, which numbers it in the index.
+fn number(post: &Post) -> Option<usize> {
+    POST_LIST.iter().position(|p| p.id == post.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_match_outranks_body_match() {
+        // post 0 has `rust` in its title, which counts three times, and post 1
+        // once in its body; both are otherwise alike
+        let index = SearchIndex {
+            text: &["", ""],
+            lengths: &[12, 12],
+            average_length: 12.0,
+            terms: &[("rust", &[(0, 3), (1, 1)]), ("web", &[(0, 1), (1, 1)])],
+        };
+        let title = index.score(0, "Rust").unwrap();
+        let body = index.score(1, "Rust").unwrap();
+        assert!(title > body, "{title} <= {body}");
+        assert_eq!(index.score(1, "rust python"), None);
+    }
+
+    #[test]
+    fn snippet_cuts_multibyte_text_at_word_boundaries() {
+        const TEXT: &str = "Ça coûte très cher à Zürich, où l’été dure peu. \
+            Überall gibt es Cafés, Brücken und Seen; jeder Spaziergang führt \
+            an einem Ufer vorbei, und die Straßenbahn fährt bis spät in die \
+            Nacht. Man sagt, Zürich sei die teuerste Stadt der Welt, aber \
+            niemand weiß es genau.";
+        let index = SearchIndex {
+            text: &[TEXT],
+            lengths: &[0],
+            average_length: 0.0,
+            terms: &[],
+        };
+
+        let fragments = index.snippet(0, "teuerste").unwrap();
+        let text: String = fragments.iter().map(|fragment| fragment.text).collect();
+        // the earliest window with the match, so it comes last
+        assert!(text.starts_with("…peu. Überall"), "{text}");
+        assert!(text.ends_with("die teuerste…"), "{text}");
+        let matched: Vec<&str> = fragments
+            .iter()
+            .filter(|fragment| fragment.matched)
+            .map(|fragment| fragment.text)
+            .collect();
+        assert_eq!(matched, ["teuerste"]);
+
+        let fragments = index.snippet(0, "Zürich").unwrap();
+        let text: String = fragments.iter().map(|fragment| fragment.text).collect();
+        assert!(text.starts_with("Ça coûte"), "{text}");
+        assert!(text.ends_with('…'), "{text}");
+        // the second `Zürich` is too far on to fit in the same snippet
+        let matched: Vec<&str> = fragments
+            .iter()
+            .filter(|fragment| fragment.matched)
+            .map(|fragment| fragment.text)
+            .collect();
+        assert_eq!(matched, ["Zürich"]);
+
+        assert_eq!(index.snippet(0, "Genf"), None);
+    }
+}
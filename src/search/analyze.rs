@@ -0,0 +1,282 @@
+//! Splitting text into search terms. The build script includes this file too,
+//! so the index and the queries run against it always agree on what a term is.
+
+/// A word of some text, and the term it is indexed under.
+pub struct Token {
+    /// Byte range of the word in the text
+    pub start: usize,
+    pub end: usize,
+    pub term: String,
+}
+
+/// The words of `text`: runs of alphanumeric characters, lowercased and
+/// stemmed, so `Searching` and `searches` are both the term `search`.
+pub fn tokens(text: &str) -> impl Iterator<Item = Token> + '_ {
+    let mut chars = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| c.is_alphanumeric())?;
+        let mut end = text.len();
+        while let Some(&(i, c)) = chars.peek() {
+            if !c.is_alphanumeric() {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        Some(Token {
+            start,
+            end,
+            term: stem(&text[start..end].to_lowercase()),
+        })
+    })
+}
+
+/// Martin Porter's stemming algorithm, for lowercase ASCII words. Anything
+/// else is left alone.
+fn stem(word: &str) -> String {
+    if word.len() <= 2 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+    let mut w = word.as_bytes().to_vec();
+    step1a(&mut w);
+    step1b(&mut w);
+    step1c(&mut w);
+    replace_longest(&mut w, STEP2, |stem| measure(stem) > 0);
+    replace_longest(&mut w, STEP3, |stem| measure(stem) > 0);
+    step4(&mut w);
+    step5(&mut w);
+    String::from_utf8(w).expect("stemming only removes and adds ASCII")
+}
+
+const STEP2: &[(&str, &str)] = &[
+    ("ational", "ate"),
+    ("tional", "tion"),
+    ("enci", "ence"),
+    ("anci", "ance"),
+    ("izer", "ize"),
+    ("abli", "able"),
+    ("alli", "al"),
+    ("entli", "ent"),
+    ("eli", "e"),
+    ("ousli", "ous"),
+    ("ization", "ize"),
+    ("ation", "ate"),
+    ("ator", "ate"),
+    ("alism", "al"),
+    ("iveness", "ive"),
+    ("fulness", "ful"),
+    ("ousness", "ous"),
+    ("aliti", "al"),
+    ("iviti", "ive"),
+    ("biliti", "ble"),
+];
+
+const STEP3: &[(&str, &str)] = &[
+    ("icate", "ic"),
+    ("ative", ""),
+    ("alize", "al"),
+    ("iciti", "ic"),
+    ("ical", "ic"),
+    ("ful", ""),
+    ("ness", ""),
+];
+
+const STEP4: &[&str] = &[
+    "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion", "ou",
+    "ism", "ate", "iti", "ous", "ive", "ize",
+];
+
+fn is_consonant(w: &[u8], i: usize) -> bool {
+    match w[i] {
+        b'a' | b'e' | b'i' | b'o' | b'u' => false,
+        b'y' => i == 0 || !is_consonant(w, i - 1),
+        _ => true,
+    }
+}
+
+/// The `m` in a word of the form `[C](VC){m}[V]`.
+fn measure(w: &[u8]) -> usize {
+    let mut i = (0..w.len())
+        .find(|&i| !is_consonant(w, i))
+        .unwrap_or(w.len());
+    let mut m = 0;
+    while i < w.len() {
+        while i < w.len() && !is_consonant(w, i) {
+            i += 1;
+        }
+        if i == w.len() {
+            break;
+        }
+        while i < w.len() && is_consonant(w, i) {
+            i += 1;
+        }
+        m += 1;
+    }
+    m
+}
+
+fn has_vowel(w: &[u8]) -> bool {
+    (0..w.len()).any(|i| !is_consonant(w, i))
+}
+
+fn ends_with_double_consonant(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 2 && w[n - 1] == w[n - 2] && is_consonant(w, n - 1)
+}
+
+/// Ends consonant-vowel-consonant, where the last consonant isn't `w`, `x`
+/// or `y`: `hop`, but not `snow`.
+fn ends_cvc(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 3
+        && is_consonant(w, n - 3)
+        && !is_consonant(w, n - 2)
+        && is_consonant(w, n - 1)
+        && !matches!(w[n - 1], b'w' | b'x' | b'y')
+}
+
+/// Replaces the longest of `rules`' suffixes that `w` ends with, if what
+/// comes before it passes `condition`. Shorter suffixes aren't tried even if
+/// it doesn't.
+fn replace_longest(w: &mut Vec<u8>, rules: &[(&str, &str)], condition: impl Fn(&[u8]) -> bool) {
+    let longest = rules
+        .iter()
+        .filter(|(suffix, _)| w.ends_with(suffix.as_bytes()))
+        .max_by_key(|(suffix, _)| suffix.len());
+    if let Some((suffix, replacement)) = longest {
+        let stem = w.len() - suffix.len();
+        if condition(&w[..stem]) {
+            w.truncate(stem);
+            w.extend_from_slice(replacement.as_bytes());
+        }
+    }
+}
+
+fn step1a(w: &mut Vec<u8>) {
+    if w.ends_with(b"sses") || w.ends_with(b"ies") {
+        w.truncate(w.len() - 2);
+    } else if w.ends_with(b"s") && !w.ends_with(b"ss") {
+        w.pop();
+    }
+}
+
+fn step1b(w: &mut Vec<u8>) {
+    if w.ends_with(b"eed") {
+        if measure(&w[..w.len() - 3]) > 0 {
+            w.pop();
+        }
+        return;
+    }
+    let suffix = if w.ends_with(b"ed") {
+        2
+    } else if w.ends_with(b"ing") {
+        3
+    } else {
+        return;
+    };
+    if !has_vowel(&w[..w.len() - suffix]) {
+        return;
+    }
+    w.truncate(w.len() - suffix);
+    if w.ends_with(b"at") || w.ends_with(b"bl") || w.ends_with(b"iz") {
+        w.push(b'e');
+    } else if ends_with_double_consonant(w) && !matches!(w[w.len() - 1], b'l' | b's' | b'z') {
+        w.pop();
+    } else if measure(w) == 1 && ends_cvc(w) {
+        w.push(b'e');
+    }
+}
+
+fn step1c(w: &mut [u8]) {
+    let n = w.len();
+    if w[n - 1] == b'y' && has_vowel(&w[..n - 1]) {
+        w[n - 1] = b'i';
+    }
+}
+
+fn step4(w: &mut Vec<u8>) {
+    let Some(suffix) = STEP4
+        .iter()
+        .filter(|suffix| w.ends_with(suffix.as_bytes()))
+        .max_by_key(|suffix| suffix.len())
+    else {
+        return;
+    };
+    let stem = &w[..w.len() - suffix.len()];
+    let allowed = *suffix != "ion" || stem.ends_with(b"s") || stem.ends_with(b"t");
+    if allowed && measure(stem) > 1 {
+        w.truncate(stem.len());
+    }
+}
+
+fn step5(w: &mut Vec<u8>) {
+    if w.ends_with(b"e") {
+        let stem = &w[..w.len() - 1];
+        let m = measure(stem);
+        if m > 1 || (m == 1 && !ends_cvc(stem)) {
+            w.pop();
+        }
+    }
+    if w.ends_with(b"ll") && measure(w) > 1 {
+        w.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stems_like_porter() {
+        // from Porter's paper and his published sample vocabulary
+        let vectors = [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("ties", "ti"),
+            ("cats", "cat"),
+            ("feed", "feed"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("motoring", "motor"),
+            ("sing", "sing"),
+            ("conflated", "conflat"),
+            ("troubled", "troubl"),
+            ("sized", "size"),
+            ("hopping", "hop"),
+            ("tanned", "tan"),
+            ("falling", "fall"),
+            ("hissing", "hiss"),
+            ("fizzed", "fizz"),
+            ("failing", "fail"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("sky", "sky"),
+            ("relational", "relat"),
+            ("conditional", "condit"),
+            ("rational", "ration"),
+            ("generalizations", "gener"),
+            ("oscillators", "oscil"),
+            ("hopeful", "hope"),
+            ("goodness", "good"),
+            ("adjustment", "adjust"),
+            ("effective", "effect"),
+            ("controlling", "control"),
+            ("probate", "probat"),
+            ("rate", "rate"),
+            ("cease", "ceas"),
+        ];
+        for (word, stemmed) in vectors {
+            assert_eq!(stem(word), stemmed, "{word}");
+        }
+    }
+
+    #[test]
+    fn leaves_short_and_non_ascii_words_alone() {
+        assert_eq!(stem("is"), "is");
+        assert_eq!(stem("cafés"), "cafés");
+        let terms: Vec<String> = tokens("Searching searches")
+            .map(|token| token.term)
+            .collect();
+        assert_eq!(terms, ["search", "search"]);
+    }
+}
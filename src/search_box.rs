@@ -0,0 +1,221 @@
+//! The search box in the navigation bar, which suggests posts, series,
+//! categories, authors and tags as you type.
+
+use dioxus::html::input_data::keyboard_types::Key;
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::authors::AUTHOR_LIST;
+use crate::category;
+use crate::post::{listed_posts, PostQuerySegments};
+use crate::series;
+use crate::Route;
+
+const MAX_SUGGESTIONS: usize = 8;
+
+#[derive(Clone)]
+struct Suggestion {
+    /// What the suggestion is, e.g. `Series`
+    kind: &'static str,
+    label: &'static str,
+    route: Route,
+}
+
+/// Every listed post, series, category, author and tag whose name contains
+/// `text`, ignoring case: post titles first, then series, categories, authors
+/// and tags.
+fn suggestions(text: &str) -> Vec<Suggestion> {
+    let needle = text.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let matches = |name: &str| name.to_lowercase().contains(&needle);
+
+    let mut found: Vec<Suggestion> = listed_posts()
+        .filter(|post| matches(post.meta.title))
+        .map(|post| Suggestion {
+            kind: "Post",
+            label: post.meta.title,
+            route: Route::Post {
+                id: post.id.to_string(),
+            },
+        })
+        .collect();
+
+    // each value is shown as its label and looked up by its key
+    let mut group =
+        |kind, mut values: Vec<(&'static str, &'static str)>, route: fn(&str) -> Route| {
+            values.sort_unstable();
+            values.dedup();
+            found.extend(values.into_iter().filter(|(label, _)| matches(label)).map(
+                |(label, key)| Suggestion {
+                    kind,
+                    label,
+                    route: route(key),
+                },
+            ));
+        };
+    let same = |value| (value, value);
+    group(
+        "Series",
+        listed_posts()
+            .filter_map(|post| post.meta.series)
+            .map(same)
+            .collect(),
+        series::route,
+    );
+    group(
+        "Category",
+        listed_posts()
+            .filter_map(|post| post.meta.category)
+            .flat_map(category::ancestors)
+            .map(same)
+            .collect(),
+        |category| Route::PostQuery {
+            query_params: PostQuerySegments {
+                category: vec![category.to_string()],
+                ..Default::default()
+            },
+        },
+    );
+    group(
+        "Author",
+        AUTHOR_LIST
+            .iter()
+            .map(|author| (author.name, author.id))
+            .collect(),
+        |id| Route::Author { id: id.to_string() },
+    );
+    group(
+        "Tag",
+        listed_posts()
+            .flat_map(|post| post.meta.tags.iter().copied())
+            .map(same)
+            .collect(),
+        |tag| Route::Tag {
+            tag: tag.to_string(),
+        },
+    );
+
+    found.truncate(MAX_SUGGESTIONS);
+    found
+}
+
+/// A text search of the posts, for when no suggestion is picked.
+fn text_search(text: &str) -> Route {
+    Route::PostQuery {
+        query_params: PostQuerySegments {
+            q: Some(text.trim().to_string()),
+            ..Default::default()
+        },
+    }
+}
+
+#[component]
+pub fn SearchBox(cx: Scope) -> Element {
+    let navigator = use_navigator(cx);
+    let text = use_state(cx, String::new);
+    // the highlighted suggestion, moved with the arrow keys
+    let selected = use_state(cx, || None::<usize>);
+    let open = use_state(cx, || false);
+
+    let suggestions = if **open {
+        suggestions(text)
+    } else {
+        Vec::new()
+    };
+    let count = suggestions.len();
+    let active = selected
+        .filter(|&index| index < count)
+        .map(|index| format!("search-suggestion-{index}"))
+        .unwrap_or_default();
+
+    let go = move |route: Route| {
+        navigator.push(route);
+        text.set(String::new());
+        selected.set(None);
+        open.set(false);
+    };
+    let picked = suggestions
+        .get(selected.unwrap_or(usize::MAX))
+        .map(|suggestion| suggestion.route.clone());
+
+    render! {
+        form {
+            class: "relative mr-4",
+            role: "search",
+            prevent_default: "onsubmit",
+            onsubmit: move |_| {
+                match picked.clone() {
+                    Some(route) => go(route),
+                    None if !text.trim().is_empty() => go(text_search(text)),
+                    None => {}
+                }
+            },
+            input {
+                class: "px-2 py-1 rounded bg-white text-gray-900 dark:bg-gray-700 dark:text-white",
+                r#type: "search",
+                placeholder: "Search posts",
+                "aria-label": "Search posts",
+                role: "combobox",
+                "aria-autocomplete": "list",
+                "aria-controls": "search-suggestions",
+                "aria-expanded": "{count > 0}",
+                "aria-activedescendant": "{active}",
+                autocomplete: "off",
+                value: "{text}",
+                oninput: move |event| {
+                    text.set(event.value.clone());
+                    selected.set(None);
+                    open.set(true);
+                },
+                onfocus: move |_| open.set(true),
+                // suggestions are picked on mousedown, which comes before this
+                onblur: move |_| open.set(false),
+                onkeydown: move |event| match event.key() {
+                    Key::ArrowDown if count > 0 => {
+                        selected.set(Some(selected.map_or(0, |index| (index + 1) % count)));
+                    }
+                    Key::ArrowUp if count > 0 => {
+                        selected.set(Some(selected.map_or(count - 1, |index| (index + count - 1) % count)));
+                    }
+                    Key::Escape => {
+                        selected.set(None);
+                        open.set(false);
+                    }
+                    _ => {}
+                },
+            }
+            if count > 0 {
+                rsx! {
+                    ul {
+                        id: "search-suggestions",
+                        class: "absolute right-0 z-10 mt-1 w-72 rounded shadow-lg bg-white text-gray-900 dark:bg-gray-700 dark:text-white",
+                        role: "listbox",
+                        for (index, suggestion) in suggestions.into_iter().enumerate() {
+                            li {
+                                id: "search-suggestion-{index}",
+                                class: if **selected == Some(index) {
+                                    "flex justify-between px-2 py-1 cursor-pointer bg-gray-200 dark:bg-gray-600"
+                                } else {
+                                    "flex justify-between px-2 py-1 cursor-pointer"
+                                },
+                                role: "option",
+                                "aria-selected": "{**selected == Some(index)}",
+                                onmousedown: move |_| go(suggestion.route.clone()),
+                                onmouseenter: move |_| selected.set(Some(index)),
+                                span {
+                                    suggestion.label
+                                }
+                                span {
+                                    class: "ml-2 text-sm text-gray-500 dark:text-gray-400",
+                                    suggestion.kind
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
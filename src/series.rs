@@ -0,0 +1,290 @@
+//! Series of posts: the page of each series, and moving between its parts
+//! with a table of contents and links to the previous and next one.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::markdown::Markdown;
+use crate::post::{listed_posts, Post, PostQuerySegments};
+use crate::post_card::PostCard;
+use crate::Route;
+
+/// Whether a series has all of its parts yet.
+// only `SERIES_LIST` builds these, so which ones are used depends on assets/
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeriesStatus {
+    Ongoing,
+    Complete,
+}
+
+impl SeriesStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ongoing => "Ongoing",
+            Self::Complete => "Complete",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SeriesMetaData {
+    /// The file stem of its entry in `assets/series/`, which is its URL
+    pub slug: &'static str,
+    /// What posts put in their `series` field
+    pub title: &'static str,
+    pub status: SeriesStatus,
+    /// Image shown at the top of the series page
+    pub cover: Option<&'static str>,
+    pub description: &'static str,
+}
+
+// generated by the build script from each file in assets/series/
+// invariants (checked by build/validate.rs): every series a post is in is
+// listed, and no two entries have the same title
+pub const SERIES_LIST: &[SeriesMetaData] = include!(concat!(env!("OUT_DIR"), "/series.rs"));
+
+/// The series posts call `title`.
+pub fn find(title: &str) -> Option<&'static SeriesMetaData> {
+    SERIES_LIST.iter().find(|series| series.title == title)
+}
+
+/// The page of the series posts call `title`.
+pub fn route(title: &str) -> Route {
+    match find(title) {
+        Some(series) => Route::Series {
+            slug: series.slug.to_string(),
+        },
+        // can't happen after the build checks, but a search still finds its posts
+        None => Route::PostQuery {
+            query_params: PostQuerySegments {
+                join: true,
+                series: vec![title.to_string()],
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// The posts of `series` in the order of their parts.
+pub fn parts(series: &str) -> Vec<&'static Post> {
+    let mut parts: Vec<&Post> = listed_posts()
+        .filter(|post| post.meta.series == Some(series))
+        .collect();
+    parts.sort_by_key(|post| post.meta.part);
+    parts
+}
+
+/// The parts before and after `post` in its series, if it has one.
+fn neighbours(post: &Post) -> (Option<&'static Post>, Option<&'static Post>) {
+    let Some(series) = post.meta.series else {
+        return (None, None);
+    };
+    let parts = parts(series);
+    let Some(index) = parts.iter().position(|part| part.id == post.id) else {
+        return (None, None);
+    };
+    let previous = index.checked_sub(1).map(|index| parts[index]);
+    (previous, parts.get(index + 1).copied())
+}
+
+/// Every part of the series `post` is in, with `post` marked.
+#[component]
+pub fn SeriesContents<'a>(cx: Scope<'a>, post: &'a Post) -> Element<'a> {
+    let series = post.meta.series;
+    let parts = series.map(parts).unwrap_or_default();
+
+    render! {
+        if let Some(series) = series {
+            rsx! {
+                nav {
+                    class: "mb-4 p-4 rounded bg-gray-200 dark:bg-gray-700",
+                    "aria-label": "Parts of {series}",
+                    p {
+                        class: "font-bold mb-2",
+                        Link {
+                            class: "text-blue-400 dark:text-orange-600",
+                            to: route(series),
+                            series
+                        }
+                    }
+                    ol {
+                        class: "list-decimal list-inside",
+                        for part in parts {
+                            li {
+                                if part.id == post.id {
+                                    rsx! {
+                                        b {
+                                            "aria-current": "page",
+                                            part.meta.title
+                                        }
+                                    }
+                                } else {
+                                    rsx! {
+                                        Link {
+                                            class: "text-blue-400 dark:text-orange-600",
+                                            to: Route::Post { id: part.id.to_string() },
+                                            part.meta.title
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Links to the previous and next parts of the series `post` is in.
+#[component]
+pub fn SeriesLinks<'a>(cx: Scope<'a>, post: &'a Post) -> Element<'a> {
+    let (previous, next) = neighbours(post);
+
+    render! {
+        if previous.is_some() || next.is_some() {
+            rsx! {
+                nav {
+                    class: "flex justify-between gap-4 mb-4",
+                    "aria-label": "Series",
+                    span {
+                        if let Some(previous) = previous {
+                            rsx! {
+                                Link {
+                                    class: "text-blue-400 dark:text-orange-600",
+                                    to: Route::Post { id: previous.id.to_string() },
+                                    rel: "prev",
+                                    u {
+                                        "<< {previous.meta.title}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    span {
+                        if let Some(next) = next {
+                            rsx! {
+                                Link {
+                                    class: "text-blue-400 dark:text-orange-600",
+                                    to: Route::Post { id: next.id.to_string() },
+                                    rel: "next",
+                                    u {
+                                        "{next.meta.title} >>"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Series(cx: Scope, slug: String) -> Element<'a> {
+    let series = SERIES_LIST.iter().find(|series| series.slug == slug);
+
+    if let Some(series) = series {
+        let parts = parts(series.title);
+        let count = parts.len();
+        let search = Route::PostQuery {
+            query_params: PostQuerySegments {
+                join: true,
+                series: vec![series.title.to_string()],
+                ..Default::default()
+            },
+        };
+
+        cx.render(rsx! {
+            div {
+                class: "bg-gray-200 dark:bg-gray-800 p-8",
+                p {
+                    class: "dark:text-white mb-2",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::PostList {},
+                        u {
+                            "<< Back to Blog"
+                        }
+                    }
+                }
+                if let Some(cover) = series.cover {
+                    rsx! {
+                        img {
+                            class: "w-full max-h-80 object-cover rounded mb-4",
+                            src: "{cover}",
+                            alt: "",
+                        }
+                    }
+                }
+                h1 {
+                    class: "text-4xl dark:text-white font-bold mb-2",
+                    series.title
+                }
+                p {
+                    class: "dark:text-white mb-4",
+                    span {
+                        class: "text-sm rounded px-2 py-0.5 bg-blue-100 text-blue-800 dark:bg-orange-900 dark:text-orange-200",
+                        series.status.label()
+                    }
+                    if count == 1 {
+                        rsx! { " • 1 part" }
+                    } else {
+                        rsx! { " • {count} parts" }
+                    }
+                    " • "
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: search,
+                        "Search this series"
+                    }
+                }
+                div {
+                    class: "mb-4 dark:text-white",
+                    Markdown {
+                        content: series.description
+                    }
+                }
+                h2 {
+                    class: "text-2xl dark:text-white font-bold mb-4",
+                    "Parts"
+                }
+                ol {
+                    for post in parts {
+                        li {
+                            PostCard {
+                                post: post,
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    } else {
+        cx.render(rsx! {
+            div {
+                class: "bg-gray-200 dark:bg-gray-800 p-8",
+                p {
+                    class: "dark:text-white",
+                    Link {
+                        class: "text-blue-400 dark:text-orange-600",
+                        to: Route::PostList {},
+                        u {
+                            "<< Back to Blog"
+                        }
+                    }
+                }
+                h1 {
+                    class: "text-3xl dark:text-white font-bold mb-4",
+                    "Series not found"
+                }
+                p {
+                    class: "dark:text-white",
+                    "The series you are looking for does not exist."
+                }
+            }
+        })
+    }
+}
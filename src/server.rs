@@ -0,0 +1,85 @@
+//! The axum server behind the `ssr` feature: the server-rendered app plus the
+//! routes that aren't pages, like the feeds.
+
+use std::net::SocketAddr;
+
+use axum::body::{self, Full, HttpBody};
+use axum::extract::RawQuery;
+use axum::http::{header, HeaderValue, Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use dioxus_fullstack::prelude::*;
+
+use crate::{feed, App, Route, ATOM_FEED, RSS_FEED};
+
+pub fn launch() {
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(async move {
+            let addr = SocketAddr::from(([127, 0, 0, 1], 8080));
+            println!("Listening on {}", addr);
+            let router = axum::Router::new()
+                .route(RSS_FEED, get(rss))
+                .route(ATOM_FEED, get(atom))
+                .serve_dioxus_application("", ServeConfigBuilder::new(App, ()))
+                .layer(middleware::from_fn(feed_links));
+            axum::Server::bind(&addr)
+                .serve(router.into_make_service())
+                .await
+                .unwrap();
+        });
+}
+
+async fn rss(RawQuery(query): RawQuery) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
+        feed::rss(&feed::for_query(RSS_FEED, query.as_deref())),
+    )
+}
+
+async fn atom(RawQuery(query): RawQuery) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+        feed::atom(&feed::for_query(ATOM_FEED, query.as_deref())),
+    )
+}
+
+/// Advertises the feeds of a page in its `<head>`, and in a `Link` header for
+/// readers that only look at the response.
+async fn feed_links<B>(request: Request<B>, next: Next<B>) -> Response {
+    let alternates = request
+        .uri()
+        .to_string()
+        .parse::<Route>()
+        .map(|route| feed::alternates(&route))
+        .unwrap_or_default();
+    let response = next.run(request).await;
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/html"));
+    if alternates.is_empty() || !is_html {
+        return response;
+    }
+
+    let (mut parts, mut body) = response.into_parts();
+    let mut html = Vec::new();
+    while let Some(chunk) = body.data().await {
+        match chunk {
+            Ok(chunk) => html.extend_from_slice(&chunk),
+            Err(error) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+            }
+        }
+    }
+    let html = feed::advertise(&String::from_utf8_lossy(&html), &alternates);
+
+    let links: Vec<String> = alternates.iter().map(feed::Alternate::header).collect();
+    // feed paths are percent-encoded, so always a valid header value
+    let value = HeaderValue::from_str(&links.join(", ")).unwrap();
+    parts.headers.append(header::LINK, value);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, body::boxed(Full::from(html)))
+}
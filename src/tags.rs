@@ -0,0 +1,136 @@
+//! The tags of posts: an index of every tag and a page for each one.
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::listing::{self, Sort};
+use crate::post::{listed_posts, Post, PostQuerySegments};
+use crate::post_card::PostCard;
+use crate::Route;
+
+/// Every tag with the number of posts that have it, by name.
+pub fn counts() -> Vec<(&'static str, usize)> {
+    let mut tags: Vec<&str> = listed_posts()
+        .flat_map(|post| post.meta.tags.iter().copied())
+        .collect();
+    tags.sort_unstable();
+
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for tag in tags {
+        match counts.last_mut() {
+            Some((last, count)) if *last == tag => *count += 1,
+            _ => counts.push((tag, 1)),
+        }
+    }
+    counts
+}
+
+#[component]
+pub fn Tags(cx: Scope) -> Element {
+    let counts = counts();
+
+    render! {
+        div {
+            class: "bg-gray-200 dark:bg-gray-800 p-8",
+            p {
+                class: "dark:text-white mb-2",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::PostList {},
+                    u {
+                        "<< Back to Blog"
+                    }
+                }
+            }
+            h1 {
+                class: "text-3xl dark:text-white font-bold mb-4",
+                "Tags"
+            }
+            if counts.is_empty() {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "No posts are tagged yet."
+                    }
+                }
+            }
+            ul {
+                class: "flex flex-wrap gap-2 dark:text-white",
+                for (tag, count) in counts {
+                    li {
+                        class: "rounded px-2 py-1 bg-gray-300 dark:bg-gray-700",
+                        Link {
+                            class: "text-blue-400 dark:text-orange-600",
+                            to: Route::Tag { tag: tag.to_string() },
+                            "#{tag}"
+                        }
+                        span {
+                            class: "ml-1 text-sm text-gray-500 dark:text-gray-400",
+                            "{count}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Tag(cx: Scope, tag: String) -> Element<'a> {
+    let mut posts: Vec<&Post> = listed_posts()
+        .filter(|post| post.meta.tags.contains(&tag.as_str()))
+        .collect();
+    listing::sort(&mut posts, Sort::Newest, None);
+    let search = Route::PostQuery {
+        query_params: PostQuerySegments {
+            join: true,
+            tag: vec![tag.clone()],
+            ..Default::default()
+        },
+    };
+
+    render! {
+        div {
+            class: "bg-gray-200 dark:bg-gray-800 p-8",
+            p {
+                class: "dark:text-white mb-2",
+                Link {
+                    class: "text-blue-400 dark:text-orange-600",
+                    to: Route::Tags {},
+                    u {
+                        "<< All tags"
+                    }
+                }
+            }
+            h1 {
+                class: "text-3xl dark:text-white font-bold mb-4",
+                "#{tag}"
+            }
+            if posts.is_empty() {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4 bg-gray-300 dark:bg-gray-700 p-4",
+                        "No posts are tagged #{tag}."
+                    }
+                }
+            } else {
+                rsx! {
+                    p {
+                        class: "dark:text-white mb-4",
+                        Link {
+                            class: "text-blue-400 dark:text-orange-600",
+                            to: search,
+                            "Search posts with this tag"
+                        }
+                    }
+                }
+            }
+            for post in posts {
+                PostCard {
+                    post: post,
+                    series_badge: true,
+                }
+            }
+        }
+    }
+}
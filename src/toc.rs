@@ -0,0 +1,153 @@
+//! The table of contents of a post, built from its headings, which highlights
+//! the section being read as the page scrolls.
+
+use std::iter::Peekable;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::markdown::{self, Heading};
+
+/// Headings a post needs before it gets a table of contents.
+const MIN_HEADINGS: usize = 2;
+
+/// Reports the id of the last heading scrolled past (or `null` above the
+/// first) whenever it changes, until the headings leave the page. Runs after
+/// `const ids = [...]`.
+const SCROLL_SPY: &str = r#"
+const headings = ids.map((id) => document.getElementById(id)).filter((heading) => heading);
+let current;
+const update = () => {
+    if (!headings.every((heading) => heading.isConnected)) {
+        removeEventListener("scroll", update);
+        return;
+    }
+    const passed = headings.filter((heading) => heading.getBoundingClientRect().top <= 96).pop();
+    const id = passed ? passed.id : null;
+    if (id !== current) {
+        current = id;
+        dioxus.send(id);
+    }
+};
+addEventListener("scroll", update, { passive: true });
+update();
+"#;
+
+/// A heading with the ones under it, up to the next heading of its level or
+/// higher.
+#[derive(Clone, PartialEq)]
+struct Section {
+    heading: Heading,
+    subsections: Vec<Section>,
+}
+
+/// The sections at the front of `headings` that are deeper than `level`.
+fn sections(headings: &mut Peekable<impl Iterator<Item = Heading>>, level: u8) -> Vec<Section> {
+    let mut found = Vec::new();
+    while let Some(heading) = headings.next_if(|heading| heading.level > level) {
+        let subsections = sections(headings, heading.level);
+        found.push(Section {
+            heading,
+            subsections,
+        });
+    }
+    found
+}
+
+/// A sidebar linking to each heading of `content`, or nothing if it has too
+/// few to be worth it.
+#[component]
+pub fn TableOfContents(cx: Scope, content: &'static str) -> Element<'a> {
+    let headings = markdown::headings(content);
+    let current = use_state(cx, || None::<String>);
+
+    // the server has no JavaScript to run, so there the current section is
+    // never known and nothing is highlighted
+    let eval = cx.consume_context::<Rc<dyn EvalProvider>>();
+    use_future(cx, (content,), |(content,)| {
+        to_owned![current];
+        async move {
+            let Some(eval) = eval else {
+                return;
+            };
+            let ids: Vec<String> = markdown::headings(content)
+                .into_iter()
+                .map(|heading| heading.id)
+                .collect();
+            // ids are slugs, which Rust and JavaScript quote the same way
+            let Ok(spy) = eval.new_evaluator(format!("const ids = {ids:?};{SCROLL_SPY}")) else {
+                return;
+            };
+            while let Ok(id) = spy.recv().await {
+                current.set(id.as_str().map(str::to_string));
+            }
+        }
+    });
+
+    let count = headings.len();
+    let sections = sections(&mut headings.into_iter().peekable(), 0);
+
+    render! {
+        if count >= MIN_HEADINGS {
+            rsx! {
+                nav {
+                    class: "mb-4 p-4 rounded bg-gray-200 dark:bg-gray-700 dark:text-white",
+                    "aria-label": "Contents",
+                    p {
+                        class: "font-bold mb-2",
+                        "Contents"
+                    }
+                    SectionList {
+                        sections: sections,
+                        current: current.get().clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SectionList(
+    cx: Scope,
+    sections: Vec<Section>,
+    /// Id of the heading being read
+    #[props(!optional)]
+    current: Option<String>,
+) -> Element<'a> {
+    render! {
+        ul {
+            class: "ml-3",
+            for section in sections {
+                li {
+                    if current.as_ref() == Some(&section.heading.id) {
+                        rsx! {
+                            a {
+                                class: "font-bold text-blue-600 dark:text-orange-400",
+                                href: "#{section.heading.id}",
+                                "aria-current": "location",
+                                "{section.heading.text}"
+                            }
+                        }
+                    } else {
+                        rsx! {
+                            a {
+                                class: "text-blue-400 dark:text-orange-600",
+                                href: "#{section.heading.id}",
+                                "{section.heading.text}"
+                            }
+                        }
+                    }
+                    if !section.subsections.is_empty() {
+                        rsx! {
+                            SectionList {
+                                sections: section.subsections.clone(),
+                                current: current.clone(),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}